@@ -3,6 +3,34 @@ pub use sea_orm_migration::prelude::*;
 mod m20240918_184436_create_team_guild;
 mod m20240918_185310_create_game;
 mod m20250329_023624_add_lfs_div_column;
+mod m20261015_000000_add_show_countdown_column;
+mod m20261015_000100_add_min_notice_column;
+mod m20261015_000200_add_game_voice_channel_column;
+mod m20261015_000250_create_rcon_macro;
+mod m20261015_000300_add_team_role_column;
+mod m20261015_000400_add_reminder_channel_column;
+mod m20261015_000500_create_steam_mapping;
+mod m20261015_000600_add_scrim_result_column;
+mod m20261015_000700_add_reservation_option_columns;
+mod m20261015_000800_add_game_reservation_id_index;
+mod m20261015_000900_add_map_vetoes_column;
+mod m20261015_001000_add_timezone_column;
+mod m20261015_001100_add_practice_config_column;
+mod m20261015_001200_create_scrim_template;
+mod m20261015_001300_add_note_column;
+mod m20261015_001400_add_created_at_column;
+mod m20261015_001500_add_game_version_column;
+mod m20261015_001600_add_opponent_details_columns;
+mod m20261015_001700_add_kind_emoji_columns;
+mod m20261015_001800_add_default_maps_column;
+mod m20261015_001900_create_series;
+mod m20261015_002000_add_game_credentials_columns;
+mod m20261015_002100_create_rcon_commands_log;
+mod m20261015_002200_add_game_discord_event_id;
+mod m20261015_002300_add_block_overlaps_column;
+mod m20261015_002400_add_backup_reservation_id_column;
+mod m20261015_002500_add_serveme_host_column;
+mod m20261015_002600_add_rcon_commands_log_game_fk;
 
 pub struct Migrator;
 
@@ -13,6 +41,34 @@ impl MigratorTrait for Migrator {
             Box::new(m20240918_184436_create_team_guild::Migration),
             Box::new(m20240918_185310_create_game::Migration),
             Box::new(m20250329_023624_add_lfs_div_column::Migration),
+            Box::new(m20261015_000000_add_show_countdown_column::Migration),
+            Box::new(m20261015_000100_add_min_notice_column::Migration),
+            Box::new(m20261015_000200_add_game_voice_channel_column::Migration),
+            Box::new(m20261015_000250_create_rcon_macro::Migration),
+            Box::new(m20261015_000300_add_team_role_column::Migration),
+            Box::new(m20261015_000400_add_reminder_channel_column::Migration),
+            Box::new(m20261015_000500_create_steam_mapping::Migration),
+            Box::new(m20261015_000600_add_scrim_result_column::Migration),
+            Box::new(m20261015_000700_add_reservation_option_columns::Migration),
+            Box::new(m20261015_000800_add_game_reservation_id_index::Migration),
+            Box::new(m20261015_000900_add_map_vetoes_column::Migration),
+            Box::new(m20261015_001000_add_timezone_column::Migration),
+            Box::new(m20261015_001100_add_practice_config_column::Migration),
+            Box::new(m20261015_001200_create_scrim_template::Migration),
+            Box::new(m20261015_001300_add_note_column::Migration),
+            Box::new(m20261015_001400_add_created_at_column::Migration),
+            Box::new(m20261015_001500_add_game_version_column::Migration),
+            Box::new(m20261015_001600_add_opponent_details_columns::Migration),
+            Box::new(m20261015_001700_add_kind_emoji_columns::Migration),
+            Box::new(m20261015_001800_add_default_maps_column::Migration),
+            Box::new(m20261015_001900_create_series::Migration),
+            Box::new(m20261015_002000_add_game_credentials_columns::Migration),
+            Box::new(m20261015_002100_create_rcon_commands_log::Migration),
+            Box::new(m20261015_002200_add_game_discord_event_id::Migration),
+            Box::new(m20261015_002300_add_block_overlaps_column::Migration),
+            Box::new(m20261015_002400_add_backup_reservation_id_column::Migration),
+            Box::new(m20261015_002500_add_serveme_host_column::Migration),
+            Box::new(m20261015_002600_add_rcon_commands_log_game_fk::Migration),
         ]
     }
 }
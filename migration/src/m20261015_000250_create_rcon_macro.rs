@@ -0,0 +1,43 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20240918_184436_create_team_guild::TeamGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RconMacro::Table)
+                    .col(big_integer(RconMacro::GuildId))
+                    .col(text(RconMacro::Name))
+                    .col(text(RconMacro::Command))
+                    .primary_key(Index::create().col(RconMacro::GuildId).col(RconMacro::Name))
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .from(RconMacro::Table, RconMacro::GuildId)
+                            .to(TeamGuild::Table, TeamGuild::Id),
+                    )
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RconMacro::Table).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RconMacro {
+    Table,
+
+    GuildId,
+    Name,
+    Command,
+}
@@ -0,0 +1,38 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SteamMapping::Table)
+                    .col(big_integer(SteamMapping::UserId).primary_key())
+                    .col(big_integer(SteamMapping::SteamId))
+                    .col(
+                        timestamp_with_time_zone(SteamMapping::LinkedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SteamMapping::Table).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SteamMapping {
+    Table,
+
+    UserId,
+    SteamId,
+    LinkedAt,
+}
@@ -0,0 +1,29 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240918_185310_create_game::Game;
+
+const INDEX_NAME: &str = "game_reservation_id_idx";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // sea-query can't express partial indexes, so this one is written by hand.
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "CREATE INDEX {INDEX_NAME} ON game (reservation_id) WHERE reservation_id IS NOT \
+                 NULL"
+            ))
+            .await
+            .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name(INDEX_NAME).table(Game::Table).to_owned())
+            .await
+    }
+}
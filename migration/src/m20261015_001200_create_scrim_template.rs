@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20240918_184436_create_team_guild::TeamGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ScrimTemplate::Table)
+                    .col(big_integer(ScrimTemplate::GuildId))
+                    .col(string(ScrimTemplate::Name))
+                    .col(small_integer(ScrimTemplate::GameFormat))
+                    .col(array(ScrimTemplate::Maps, ColumnType::string(None)))
+                    .primary_key(
+                        Index::create()
+                            .col(ScrimTemplate::GuildId)
+                            .col(ScrimTemplate::Name),
+                    )
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .from(ScrimTemplate::Table, ScrimTemplate::GuildId)
+                            .to(TeamGuild::Table, TeamGuild::Id),
+                    )
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ScrimTemplate::Table).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum ScrimTemplate {
+    Table,
+
+    GuildId,
+    Name,
+    GameFormat,
+    Maps,
+}
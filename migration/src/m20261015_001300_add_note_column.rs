@@ -0,0 +1,29 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20240918_185310_create_game::Game;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Game::Table)
+                    .add_column(text_null(Note))
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(Table::alter().table(Game::Table).drop_column(Note).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub struct Note;
@@ -0,0 +1,76 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::{m20240918_184436_create_team_guild::TeamGuild, m20240918_185310_create_game::Game};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Series::Table)
+                    .col(pk_auto(Series::Id))
+                    .col(big_integer(Series::GuildId))
+                    .col(small_integer(Series::N))
+                    .col(
+                        timestamp_with_time_zone(Series::CreatedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .from(Series::Table, Series::GuildId)
+                            .to(TeamGuild::Table, TeamGuild::Id),
+                    )
+                    .take(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Game::Table)
+                    .add_column(integer_null(SeriesId))
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .from_tbl(Game::Table)
+                            .from_col(SeriesId)
+                            .to_tbl(Series::Table)
+                            .to_col(Series::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Game::Table)
+                    .drop_column(SeriesId)
+                    .take(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Series::Table).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Series {
+    Table,
+
+    Id,
+    GuildId,
+    N,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+pub struct SeriesId;
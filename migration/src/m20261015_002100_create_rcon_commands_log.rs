@@ -0,0 +1,68 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::m20240918_184436_create_team_guild::TeamGuild;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RconCommandsLog::Table)
+                    .col(
+                        big_integer(RconCommandsLog::Id)
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(big_integer(RconCommandsLog::GuildId))
+                    .col(timestamp_with_time_zone(RconCommandsLog::GameTimestamp))
+                    .col(text(RconCommandsLog::Command))
+                    .col(text(RconCommandsLog::ResponseTruncated))
+                    .col(big_integer(RconCommandsLog::ExecutedBy))
+                    .col(
+                        timestamp_with_time_zone(RconCommandsLog::ExecutedAt)
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKeyCreateStatement::new()
+                            .from(RconCommandsLog::Table, RconCommandsLog::GuildId)
+                            .to(TeamGuild::Table, TeamGuild::Id),
+                    )
+                    .take(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-rcon_commands_log-game")
+                    .table(RconCommandsLog::Table)
+                    .col(RconCommandsLog::GuildId)
+                    .col(RconCommandsLog::GameTimestamp)
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RconCommandsLog::Table).take())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum RconCommandsLog {
+    Table,
+
+    Id,
+    GuildId,
+    GameTimestamp,
+    Command,
+    ResponseTruncated,
+    ExecutedBy,
+    ExecutedAt,
+}
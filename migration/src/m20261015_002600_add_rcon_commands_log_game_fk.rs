@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+use crate::{
+    m20240918_185310_create_game::Game, m20261015_002100_create_rcon_commands_log::RconCommandsLog,
+};
+
+const FOREIGN_KEY_NAME: &str = "fk-rcon_commands_log-game";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // commands logged for games that have since been deleted would
+        // otherwise be inherited by new games at the same time.
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DELETE FROM rcon_commands_log AS log WHERE NOT EXISTS (SELECT 1 FROM game WHERE \
+                 game.guild_id = log.guild_id AND game.timestamp = log.game_timestamp)",
+            )
+            .await?;
+
+        // cascading updates move the log along with a rescheduled game.
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name(FOREIGN_KEY_NAME)
+                    .from(
+                        RconCommandsLog::Table,
+                        (RconCommandsLog::GuildId, RconCommandsLog::GameTimestamp),
+                    )
+                    .to(Game::Table, (Game::GuildId, Game::Timestamp))
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .take(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name(FOREIGN_KEY_NAME)
+                    .table(RconCommandsLog::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -2,13 +2,12 @@ use std::sync::LazyLock;
 
 use paste::paste;
 use regex::Regex;
-use time::{Date, Duration, OffsetDateTime, Time, macros::time};
-
-use crate::utils::OffsetDateTimeEtExt;
+use time::{Date, Duration, Month, Time, macros::time};
 
 pub fn split_datetime_query(query: &str) -> (String, String, String) {
-    static REGEX: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"^([a-z]+)?\s*(\d[a-z0-9]*)?$").unwrap());
+    static REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^([a-z]+|\d{1,2}[/-]\d{1,2})?\s*(\d[a-z0-9:. ]*)?$").unwrap()
+    });
 
     let query = query.trim().to_lowercase();
 
@@ -22,16 +21,76 @@ pub fn split_datetime_query(query: &str) -> (String, String, String) {
     }
 }
 
-pub fn day_aliases(date: Date) -> &'static [&'static str] {
+static DATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,2})[/-](\d{1,2})$").unwrap());
+
+pub fn is_date_query(day_query: &str) -> bool {
+    DATE_REGEX.is_match(day_query)
+}
+
+pub fn parse_date_query(day_query: &str, today: Date) -> Option<Date> {
+    let captures = DATE_REGEX.captures(day_query)?;
+
+    upcoming_date(captures[1].parse().ok()?, captures[2].parse().ok()?, today)
+}
+
+fn upcoming_date(month: u8, day: u8, today: Date) -> Option<Date> {
+    let month = Month::try_from(month).ok()?;
+
+    let date = Date::from_calendar_date(today.year(), month, day).ok()?;
+
+    if date >= today {
+        Some(date)
+    } else if u8::from(month) < u8::from(today.month()) {
+        // an earlier month refers to next year, e.g. 01/05 typed in december
+        Date::from_calendar_date(today.year() + 1, month, day).ok()
+    } else {
+        None
+    }
+}
+
+pub fn parse_time_query(time_query: &str) -> Option<Time> {
+    static REGEX: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^(\d{1,2})(?::?(\d{2}))?\s*(am|a\.m\.|pm|p\.m\.)?$").unwrap()
+    });
+
+    let captures = REGEX.captures(time_query.trim())?;
+
+    let hour = captures[1].parse::<u8>().ok()?;
+    let minute = captures
+        .get(2)
+        .map(|m| m.as_str().parse::<u8>())
+        .transpose()
+        .ok()?;
+
+    let hour = if let Some(suffix) = captures.get(3) {
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+
+        (hour % 12)
+            + if suffix.as_str().starts_with('p') {
+                12
+            } else {
+                0
+            }
+    } else {
+        // without an am/pm suffix, only treat complete 24-hour times as typed
+        minute?;
+        hour
+    };
+
+    Time::from_hms(hour, minute.unwrap_or(0), 0).ok()
+}
+
+pub fn day_aliases(date: Date, today: Date) -> &'static [&'static str] {
     macro_rules! aliases {
             ($($weekday:ident),*) => {
                 paste! {
-                    let now_date = OffsetDateTime::now_et().date();
-
                     match (
                         date.weekday(),
-                        date == now_date,
-                        date == now_date.next_day().unwrap(),
+                        date == today,
+                        date == today.next_day().unwrap(),
                     ) {
                         $(
                             (time::Weekday::$weekday, true, false) => {
@@ -152,11 +211,11 @@ pub const fn time_aliases(time: Time) -> &'static [&'static str] {
     }
 }
 
-pub fn day_choices() -> impl Iterator<Item = (Date, &'static [&'static str])> {
+pub fn day_choices(today: Date) -> impl Iterator<Item = (Date, &'static [&'static str])> {
     (0..=7).map(move |i| {
-        let date = OffsetDateTime::now_et().date() + Duration::days(i);
+        let date = today + Duration::days(i);
 
-        (date, day_aliases(date))
+        (date, day_aliases(date, today))
     })
 }
 
@@ -172,3 +231,61 @@ pub static TIME_CHOICES: LazyLock<Vec<(Time, &'static [&'static str])>> = LazyLo
         })
         .collect()
 });
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn parse_time_query_accepts_twelve_hour_times() {
+        assert_eq!(parse_time_query("845pm"), Some(time!(20:45)));
+        assert_eq!(parse_time_query("8:45 p.m."), Some(time!(20:45)));
+        assert_eq!(parse_time_query("9pm"), Some(time!(21:00)));
+        assert_eq!(parse_time_query("12am"), Some(time!(00:00)));
+        assert_eq!(parse_time_query("12:30pm"), Some(time!(12:30)));
+        assert_eq!(parse_time_query("11:15 a.m."), Some(time!(11:15)));
+    }
+
+    #[test]
+    fn parse_time_query_accepts_complete_24_hour_times() {
+        assert_eq!(parse_time_query("20:45"), Some(time!(20:45)));
+        assert_eq!(parse_time_query("2045"), Some(time!(20:45)));
+        assert_eq!(parse_time_query("0:00"), Some(time!(00:00)));
+    }
+
+    #[test]
+    fn parse_time_query_rejects_partial_or_invalid_times() {
+        assert_eq!(parse_time_query(""), None);
+        assert_eq!(parse_time_query("8"), None);
+        assert_eq!(parse_time_query("13pm"), None);
+        assert_eq!(parse_time_query("0am"), None);
+        assert_eq!(parse_time_query("24:00"), None);
+        assert_eq!(parse_time_query("8:60pm"), None);
+        assert_eq!(parse_time_query("8:4"), None);
+    }
+
+    #[test]
+    fn parse_date_query_picks_the_upcoming_date() {
+        let today = date!(2024 - 10 - 16);
+
+        assert_eq!(parse_date_query("10/16", today), Some(today));
+        assert_eq!(
+            parse_date_query("10-20", today),
+            Some(date!(2024 - 10 - 20))
+        );
+        assert_eq!(parse_date_query("12/1", today), Some(date!(2024 - 12 - 01)));
+        assert_eq!(parse_date_query("1/5", today), Some(date!(2025 - 01 - 05)));
+    }
+
+    #[test]
+    fn parse_date_query_rejects_past_or_invalid_dates() {
+        let today = date!(2024 - 10 - 16);
+
+        assert_eq!(parse_date_query("10/15", today), None);
+        assert_eq!(parse_date_query("2/30", today), None);
+        assert_eq!(parse_date_query("13/1", today), None);
+        assert_eq!(parse_date_query("tue", today), None);
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use time::{OffsetDateTime, UtcOffset};
+
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, Clone, Default)]
+pub struct Calendar {
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub uid: String,
+    pub starts_at: OffsetDateTime,
+    pub ends_at: OffsetDateTime,
+    pub summary: String,
+    pub description: Option<String>,
+    pub url: Option<String>,
+}
+
+impl Display for Calendar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let now = utc_string(OffsetDateTime::now_utc());
+
+        write_line(f, "BEGIN:VCALENDAR")?;
+        write_line(f, "VERSION:2.0")?;
+        write_line(f, "PRODID:-//scheduletf//Schedule//EN")?;
+        write_line(f, "CALSCALE:GREGORIAN")?;
+
+        for event in &self.events {
+            write_line(f, "BEGIN:VEVENT")?;
+            write_line(f, &format!("UID:{}", escape(&event.uid)))?;
+            write_line(f, &format!("DTSTAMP:{now}"))?;
+            write_line(f, &format!("DTSTART:{}", utc_string(event.starts_at)))?;
+            write_line(f, &format!("DTEND:{}", utc_string(event.ends_at)))?;
+            write_line(f, &format!("SUMMARY:{}", escape(&event.summary)))?;
+
+            if let Some(description) = &event.description {
+                write_line(f, &format!("DESCRIPTION:{}", escape(description)))?;
+            }
+
+            if let Some(url) = &event.url {
+                write_line(f, &format!("URL:{url}"))?;
+            }
+
+            write_line(f, "END:VEVENT")?;
+        }
+
+        write_line(f, "END:VCALENDAR")
+    }
+}
+
+/// Write a content line, folding it so no physical line exceeds 75 octets
+/// (RFC 5545 §3.1).
+fn write_line(f: &mut Formatter<'_>, line: &str) -> fmt::Result {
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            f.write_str("\r\n ")?;
+            octets = 1;
+        }
+
+        f.write_char(c)?;
+        octets += c.len_utf8();
+    }
+
+    f.write_str("\r\n")
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn utc_string(date_time: OffsetDateTime) -> String {
+    let utc = date_time.to_offset(UtcOffset::UTC);
+
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        utc.year(),
+        u8::from(utc.month()),
+        utc.day(),
+        utc.hour(),
+        utc.minute(),
+        utc.second(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn calendar_snapshot() {
+        let calendar = Calendar {
+            events: vec![Event {
+                uid: "1-1700000000@scheduletf".to_owned(),
+                starts_at: datetime!(2024-10-01 21:00 -4),
+                ends_at: datetime!(2024-10-01 23:00 -4),
+                summary: "Scrim vs. Team A, Team B".to_owned(),
+                description: Some(
+                    "connect 1.2.3.4:27015; password \"pw\"\nMap(s): cp_process_f12".to_owned(),
+                ),
+                url: Some("https://rgl.gg/Public/Match?m=1".to_owned()),
+            }],
+        };
+
+        // DTSTAMP is the time of export, so it can't be snapshotted.
+        let ics = calendar
+            .to_string()
+            .split("\r\n")
+            .filter(|line| !line.starts_with("DTSTAMP:"))
+            .collect::<Vec<_>>()
+            .join("\r\n");
+
+        assert_eq!(
+            ics,
+            [
+                "BEGIN:VCALENDAR",
+                "VERSION:2.0",
+                "PRODID:-//scheduletf//Schedule//EN",
+                "CALSCALE:GREGORIAN",
+                "BEGIN:VEVENT",
+                "UID:1-1700000000@scheduletf",
+                "DTSTART:20241002T010000Z",
+                "DTEND:20241002T030000Z",
+                r"SUMMARY:Scrim vs. Team A\, Team B",
+                r#"DESCRIPTION:connect 1.2.3.4:27015\; password "pw"\nMap(s): cp_process_f12"#,
+                "URL:https://rgl.gg/Public/Match?m=1",
+                "END:VEVENT",
+                "END:VCALENDAR",
+                "",
+            ]
+            .join("\r\n")
+        );
+    }
+
+    #[test]
+    fn long_lines_are_folded_at_75_octets() {
+        struct Line<'a>(&'a str);
+
+        impl Display for Line<'_> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                write_line(f, self.0)
+            }
+        }
+
+        let line = "é".repeat(50);
+        let folded = Line(&line).to_string();
+
+        for physical in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(physical.len() <= MAX_LINE_OCTETS, "{physical:?}");
+        }
+
+        assert_eq!(folded.replace("\r\n ", ""), format!("{line}\r\n"));
+    }
+
+    #[test]
+    fn utc_string_converts_offset() {
+        assert_eq!(
+            utc_string(datetime!(2024-12-31 20:30:05 -5)),
+            "20250101T013005Z"
+        );
+    }
+}
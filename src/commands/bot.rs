@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use sea_orm::{
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect,
+    sea_query::{Asterisk, Expr, Func},
+};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::Command;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult, STARTED_AT,
+    entities::{game, team_guild},
+    utils::embed,
+};
+
+#[derive(Debug, Command)]
+pub enum BotCommand {
+    /// Show the bot's version, uptime, and usage.
+    Info,
+}
+
+fn uptime_string(uptime: Duration) -> String {
+    let secs = uptime.as_secs();
+
+    let (days, hours, minutes) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60);
+
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+impl BotCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Info => {
+                interaction.defer_ephemeral(ctx).await?;
+
+                let guilds = team_guild::Entity::find().count(&bot.db).await?;
+
+                let upcoming_games = game::Entity::find()
+                    .filter(game::Column::Timestamp.gt(OffsetDateTime::now_utc()))
+                    .count(&bot.db)
+                    .await?;
+
+                let (guilds_with_games, total_games) = game::Entity::find()
+                    .select_only()
+                    .expr(Func::count_distinct(Expr::col(game::Column::GuildId)))
+                    .expr(Func::count(Expr::col(Asterisk)))
+                    .into_tuple::<(i64, i64)>()
+                    .one(&bot.db)
+                    .await?
+                    .unwrap_or_default();
+
+                let embed = embed("🤖 Bot Info")
+                    .field("Version", format!("`{}`", env!("CARGO_PKG_VERSION")), true)
+                    .field("Uptime", uptime_string(STARTED_AT.elapsed()), true)
+                    .field("Guilds", guilds.to_string(), true)
+                    .field("Upcoming Games", upcoming_games.to_string(), true)
+                    .field("Total Games", total_games.to_string(), true)
+                    .field("Guilds With Games", guilds_with_games.to_string(), true);
+
+                interaction
+                    .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,12 +1,21 @@
+use paste::paste;
 use sea_orm::{ActiveModelTrait, IntoActiveModel};
-use serenity::all::{CommandInteraction, Context, CreateInteractionResponse};
-use serenity_commands::{Command, SubCommandGroup};
+use serenity::all::{
+    AutocompleteChoice, ChannelId, CommandInteraction, Context, CreateAutocompleteResponse,
+    CreateEmbed, CreateInteractionResponse, Permissions,
+};
+use serenity_commands::{Command, SubCommand, SubCommandGroup};
+use tracing::warn;
 
 use crate::{
     Bot, BotResult,
-    entities::{GameFormat, ScheduleChannelId, ServemeApiKey},
-    rgl::{RglSeason, RglTeam, RglTeamId},
-    utils::{create_message, success_embed},
+    entities::{
+        GameFormat, GameVoiceChannelId, MapList, ReminderChannelId, ScheduleChannelId,
+        ServemeApiKey, ServemeHost, TeamRoleId, Timezone, team_guild,
+    },
+    error::BotError,
+    rgl::{RglSeason, RglTeam, RglTeamId, TeamSearchResult},
+    utils::{create_message, success_embed, warning_embed},
 };
 
 #[derive(Debug, Command)]
@@ -15,6 +24,7 @@ pub enum ConfigCommand {
     Show,
 
     /// Set a configuration option.
+    #[command(autocomplete)]
     Set(ConfigSetCommand),
 }
 
@@ -22,26 +32,39 @@ macro_rules! config_commands {
     (
         $(
             $doc:literal
-            $name:ident { $field:ident : $field_ty:ty },
+            $(#[$attr:meta])*
+            $name:ident { $(#[$field_attr:meta])* $field:ident : $field_ty:ty },
         )*
     ) => {
-        #[derive(Debug, SubCommandGroup)]
-        pub enum ConfigSetCommand {
+        paste! {
+            #[derive(Debug, SubCommandGroup)]
+            pub enum ConfigSetCommand {
+                $(
+                    #[doc = concat!("Set the ", $doc, ".")]
+                    $(#[$attr])*
+                    $name([<ConfigSet $name Command>]),
+                )*
+            }
+
             $(
-                #[doc = concat!("Set the ", $doc, ".")]
-                $name {
+                #[derive(Debug, SubCommand)]
+                pub struct [<ConfigSet $name Command>] {
                     #[doc = concat!("The ", stringify!($doc), ". If left empty, this unsets the option.")]
+                    $(#[$field_attr])*
                     $field: Option<$field_ty>,
-                },
+                }
             )*
         }
     };
 }
 
 config_commands! {
-    "na.serveme.tf API key"
+    "serveme.tf API key"
     Serveme { key: ServemeApiKey },
 
+    "serveme.tf host the API key belongs to (defaults to the bot's host, e.g. na.serveme.tf)"
+    ServemeHost { host: ServemeHost },
+
     "default game format"
     GameFormat { format: GameFormat },
 
@@ -49,13 +72,183 @@ config_commands! {
     ScheduleChannel { channel: ScheduleChannelId },
 
     "RGL team ID"
-    RglTeam { id: RglTeamId },
+    #[command(autocomplete)]
+    RglTeam {
+        #[command(autocomplete)]
+        id: RglTeamId
+    },
 
     "division to use in LFS messages"
     ScrimDivision { division: String },
+
+    "visibility of countdowns in the schedule"
+    ShowCountdown { enabled: bool },
+
+    "whether to block hosting games whose reservations overlap, instead of warning"
+    BlockOverlaps { enabled: bool },
+
+    "minimum notice (in minutes) required to host a game"
+    MinNotice { minutes: i32 },
+
+    "voice channel to show STV info in during games"
+    GameVoiceChannel { channel: GameVoiceChannelId },
+
+    "team role to ping in game reminders"
+    TeamRole { role: TeamRoleId },
+
+    "channel to post game reminders in (defaults to the schedule channel)"
+    ReminderChannel { channel: ReminderChannelId },
+
+    "IANA timezone to display times in (e.g. America/New_York)"
+    Timezone { timezone: Timezone },
+
+    "serveme.tf server config ID offered first by /game practice-config"
+    PracticeConfig { id: i32 },
+
+    "space-separated map rotation used when hosting without maps"
+    #[command(autocomplete)]
+    DefaultMaps {
+        #[command(autocomplete)]
+        maps: MapList
+    },
+
+    "emoji shown for scrims in the schedule"
+    ScrimEmoji { emoji: String },
+
+    "emoji shown for matches in the schedule"
+    MatchEmoji { emoji: String },
+}
+
+/// Check that the bot has the `required` permissions in `channel_id`, so
+/// that a channel it can't use is rejected up front rather than failing later.
+async fn ensure_permissions(
+    ctx: &Context,
+    channel_id: ChannelId,
+    required: Permissions,
+    required_names: &'static str,
+) -> BotResult {
+    let insufficient = BotError::InsufficientPermissions {
+        channel_id,
+        required: required_names,
+    };
+
+    let Some(channel) = ctx.http.get_channel(channel_id).await?.guild() else {
+        return Err(insufficient);
+    };
+
+    let bot_id = ctx.cache.current_user().id;
+
+    let member = channel.guild_id.member(ctx, bot_id).await?;
+
+    // guilds aren't cached, since the bot doesn't request the `GUILDS` intent,
+    // so the roles are fetched to check against.
+    let permissions = channel
+        .guild_id
+        .to_partial_guild(ctx)
+        .await?
+        .user_permissions_in(&channel, &member);
+
+    if !permissions.contains(required) {
+        return Err(insufficient);
+    }
+
+    Ok(())
+}
+
+/// Check that the bot can post embeds in `channel_id`.
+async fn ensure_can_post(ctx: &Context, channel_id: ChannelId) -> BotResult {
+    ensure_permissions(
+        ctx,
+        channel_id,
+        Permissions::SEND_MESSAGES | Permissions::EMBED_LINKS,
+        "Send Messages, Embed Links",
+    )
+    .await
+}
+
+/// Check that the bot can set the status of the voice channel `channel_id`.
+/// Discord also requires Manage Channels when the bot isn't connected to it.
+async fn ensure_can_set_voice_status(ctx: &Context, channel_id: ChannelId) -> BotResult {
+    ensure_permissions(
+        ctx,
+        channel_id,
+        Permissions::MANAGE_CHANNELS | Permissions::SET_VOICE_CHANNEL_STATUS,
+        "Manage Channels, Set Voice Channel Status",
+    )
+    .await
+}
+
+async fn detect_game_format(team_id: RglTeamId) -> BotResult<GameFormat> {
+    let team = RglTeam::get(team_id).await?;
+
+    let season = RglSeason::get(team.season_id).await?;
+
+    Ok(season.format_name)
+}
+
+/// Store the RGL team ID and the game format detected from it. The ID is saved
+/// even if detection failed, in which case a warning is returned to show
+/// alongside the update. Unsetting the ID leaves the format alone.
+fn set_rgl_team(
+    guild: &mut team_guild::ActiveModel,
+    team_id: Option<RglTeamId>,
+    detected_format: Option<BotResult<GameFormat>>,
+) -> Option<CreateEmbed> {
+    guild.rgl_team_id.set_if_not_equals(team_id);
+
+    match detected_format {
+        Some(Ok(format)) => {
+            guild.game_format.set_if_not_equals(Some(format));
+
+            None
+        }
+        Some(Err(error)) => {
+            warn!(
+                ?team_id,
+                ?error,
+                "failed to detect game format from RGL team"
+            );
+
+            Some(warning_embed(format!(
+                "Couldn't detect the game format from the RGL team, so it was left unchanged: {error}"
+            )))
+        }
+        None => None,
+    }
+}
+
+/// Warn about default maps that aren't in the guild's format's official map
+/// pool, as they are likely typos.
+fn unofficial_maps_warning(guild: &team_guild::Model) -> Option<CreateEmbed> {
+    let unofficial = guild
+        .default_maps
+        .iter()
+        .flat_map(|maps| maps.iter())
+        .filter(|map| !map.is_official(guild.game_format))
+        .map(|map| format!("`{map}`"))
+        .collect::<Vec<_>>();
+
+    (!unofficial.is_empty()).then(|| {
+        warning_embed(format!(
+            "These default maps aren't in the official{} map pool: {}",
+            guild
+                .game_format
+                .map(|format| format!(" {format}"))
+                .unwrap_or_default(),
+            unofficial.join(", ")
+        ))
+    })
 }
 
 impl ConfigCommand {
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Set(_) => true,
+            Self::Show => false,
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
     pub async fn run(
         self,
         bot: &Bot,
@@ -66,55 +259,129 @@ impl ConfigCommand {
 
         match self {
             Self::Show => {
+                let upcoming_games = guild.upcoming_game_count(&tx).await?;
+                let total_games = guild.game_count(&tx).await?;
+
+                let embed = guild.config_embed().field(
+                    "Games",
+                    format!("{upcoming_games} upcoming, {total_games} total games on record"),
+                    false,
+                );
+
                 interaction
                     .create_response(
                         &ctx,
-                        CreateInteractionResponse::Message(
-                            create_message().embed(guild.config_embed()),
-                        ),
+                        CreateInteractionResponse::Message(create_message().embed(embed)),
                     )
                     .await?;
             }
             Self::Set(cmd) => {
                 let mut guild = guild.into_active_model();
 
+                let setting_default_maps = matches!(cmd, ConfigSetCommand::DefaultMaps(_));
+
+                let mut format_warning = None;
+
                 match cmd {
-                    ConfigSetCommand::Serveme { key } => {
+                    ConfigSetCommand::Serveme(ConfigSetServemeCommand { key }) => {
                         guild.serveme_api_key.set_if_not_equals(key);
                     }
-                    ConfigSetCommand::GameFormat { format } => {
+                    ConfigSetCommand::ServemeHost(ConfigSetServemeHostCommand { host }) => {
+                        guild.serveme_host.set_if_not_equals(host);
+                    }
+                    ConfigSetCommand::GameFormat(ConfigSetGameFormatCommand { format }) => {
                         guild.game_format.set_if_not_equals(format);
                     }
-                    ConfigSetCommand::ScheduleChannel { channel } => {
+                    ConfigSetCommand::ScheduleChannel(ConfigSetScheduleChannelCommand {
+                        channel,
+                    }) => {
+                        if let Some(channel) = channel {
+                            ensure_can_post(ctx, *channel).await?;
+                        }
+
                         guild.schedule_channel_id.set_if_not_equals(channel);
                     }
-                    ConfigSetCommand::RglTeam { id } => {
-                        guild.rgl_team_id.set_if_not_equals(id);
+                    ConfigSetCommand::RglTeam(ConfigSetRglTeamCommand { id }) => {
+                        let detected_format = if let Some(team_id) = id {
+                            Some(detect_game_format(team_id).await)
+                        } else {
+                            None
+                        };
 
-                        if let Some(team_id) = id {
-                            let team = RglTeam::get(team_id).await?;
+                        format_warning = set_rgl_team(&mut guild, id, detected_format);
+                    }
+                    ConfigSetCommand::ScrimDivision(ConfigSetScrimDivisionCommand { division }) => {
+                        guild.scrim_division.set_if_not_equals(division);
+                    }
+                    ConfigSetCommand::ShowCountdown(ConfigSetShowCountdownCommand { enabled }) => {
+                        guild.show_countdown.set_if_not_equals(enabled);
+                    }
+                    ConfigSetCommand::BlockOverlaps(ConfigSetBlockOverlapsCommand { enabled }) => {
+                        guild.block_overlaps.set_if_not_equals(enabled);
+                    }
+                    ConfigSetCommand::MinNotice(ConfigSetMinNoticeCommand { minutes }) => {
+                        if minutes.is_some_and(i32::is_negative) {
+                            return Err(BotError::NegativeMinNotice);
+                        }
 
-                            let season = RglSeason::get(team.season_id).await?;
+                        guild.min_notice_minutes.set_if_not_equals(minutes);
+                    }
+                    ConfigSetCommand::GameVoiceChannel(ConfigSetGameVoiceChannelCommand {
+                        channel,
+                    }) => {
+                        if let Some(channel) = channel {
+                            ensure_can_set_voice_status(ctx, *channel).await?;
+                        }
 
-                            guild
-                                .game_format
-                                .set_if_not_equals(Some(season.format_name));
+                        guild.game_voice_channel_id.set_if_not_equals(channel);
+                    }
+                    ConfigSetCommand::TeamRole(ConfigSetTeamRoleCommand { role }) => {
+                        guild.team_role_id.set_if_not_equals(role);
+                    }
+                    ConfigSetCommand::ReminderChannel(ConfigSetReminderChannelCommand {
+                        channel,
+                    }) => {
+                        if let Some(channel) = channel {
+                            ensure_can_post(ctx, *channel).await?;
                         }
+
+                        guild.reminder_channel_id.set_if_not_equals(channel);
                     }
-                    ConfigSetCommand::ScrimDivision { division } => {
-                        guild.scrim_division.set_if_not_equals(division);
+                    ConfigSetCommand::Timezone(ConfigSetTimezoneCommand { timezone }) => {
+                        guild.timezone.set_if_not_equals(timezone);
+                    }
+                    ConfigSetCommand::PracticeConfig(ConfigSetPracticeConfigCommand { id }) => {
+                        guild.practice_config_id.set_if_not_equals(id);
+                    }
+                    ConfigSetCommand::DefaultMaps(ConfigSetDefaultMapsCommand { maps }) => {
+                        guild.default_maps.set_if_not_equals(maps);
+                    }
+                    ConfigSetCommand::ScrimEmoji(ConfigSetScrimEmojiCommand { emoji }) => {
+                        guild.scrim_emoji.set_if_not_equals(emoji);
+                    }
+                    ConfigSetCommand::MatchEmoji(ConfigSetMatchEmojiCommand { emoji }) => {
+                        guild.match_emoji.set_if_not_equals(emoji);
                     }
                 }
 
                 let guild = guild.update(&tx).await?;
 
+                let mut embeds = vec![success_embed("Configuration updated.")];
+
+                embeds.extend(format_warning);
+
+                if let Some(warning) =
+                    unofficial_maps_warning(&guild).filter(|_| setting_default_maps)
+                {
+                    embeds.push(warning);
+                }
+
+                embeds.push(guild.config_embed());
+
                 interaction
                     .create_response(
                         &ctx,
-                        CreateInteractionResponse::Message(create_message().embeds(vec![
-                            success_embed("Configuration updated."),
-                            guild.config_embed(),
-                        ])),
+                        CreateInteractionResponse::Message(create_message().embeds(embeds)),
                     )
                     .await?;
 
@@ -125,3 +392,114 @@ impl ConfigCommand {
         Ok(())
     }
 }
+
+impl ConfigCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let id = match self {
+            Self::Set(ConfigSetCommandAutocomplete::RglTeam(
+                ConfigSetRglTeamCommandAutocomplete::Id { id },
+            )) => id,
+            Self::Set(ConfigSetCommandAutocomplete::DefaultMaps(
+                ConfigSetDefaultMapsCommandAutocomplete::Maps { maps },
+            )) => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                return guild.autocomplete_maps(ctx, interaction, None, &maps).await;
+            }
+        };
+
+        let query = id.trim();
+
+        let choices = if query.is_empty() {
+            Vec::new()
+        } else if let Ok(team_id) = query.parse() {
+            // a typed ID is only offered if it belongs to a real team.
+            RglTeam::get(RglTeamId(team_id))
+                .await
+                .map(|team| AutocompleteChoice::new(format!("{} ({team_id})", team.name), team_id))
+                .into_iter()
+                .collect()
+        } else {
+            TeamSearchResult::search(query)
+                .await?
+                .iter()
+                .take(25)
+                .map(|team| {
+                    AutocompleteChoice::new(
+                        format!("{} ({})", team.name, team.team_id),
+                        team.team_id.0,
+                    )
+                })
+                .collect()
+        };
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::ActiveValue::{Set, Unchanged};
+
+    use super::*;
+
+    fn guild() -> team_guild::ActiveModel {
+        team_guild::ActiveModel {
+            rgl_team_id: Unchanged(None),
+            game_format: Unchanged(Some(GameFormat::Highlander)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn set_rgl_team_sets_detected_format() {
+        let mut guild = guild();
+
+        let warning = set_rgl_team(&mut guild, Some(RglTeamId(1)), Some(Ok(GameFormat::Sixes)));
+
+        assert!(warning.is_none());
+        assert_eq!(guild.rgl_team_id, Set(Some(RglTeamId(1))));
+        assert_eq!(guild.game_format, Set(Some(GameFormat::Sixes)));
+    }
+
+    #[test]
+    fn set_rgl_team_saves_id_when_detection_fails() {
+        let mut guild = guild();
+
+        let warning = set_rgl_team(
+            &mut guild,
+            Some(RglTeamId(1)),
+            Some(Err(BotError::NoRglTeam)),
+        );
+
+        assert!(warning.is_some());
+        assert_eq!(guild.rgl_team_id, Set(Some(RglTeamId(1))));
+        assert_eq!(guild.game_format, Unchanged(Some(GameFormat::Highlander)));
+    }
+
+    #[test]
+    fn unsetting_rgl_team_keeps_format() {
+        let mut guild = team_guild::ActiveModel {
+            rgl_team_id: Unchanged(Some(RglTeamId(1))),
+            ..guild()
+        };
+
+        assert!(set_rgl_team(&mut guild, None, None).is_none());
+        assert_eq!(guild.rgl_team_id, Set(None));
+        assert_eq!(guild.game_format, Unchanged(Some(GameFormat::Highlander)));
+    }
+}
@@ -0,0 +1,51 @@
+use std::convert::identity;
+
+use serenity::{
+    all::{CommandInteraction, Context, CreateAttachment, EditInteractionResponse},
+    futures::{StreamExt, TryStreamExt, stream},
+};
+use serenity_commands::SubCommand;
+
+use crate::{Bot, BotResult, calendar::Calendar, entities::game::ScrimOrMatch};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct CalendarCommand;
+
+impl CalendarCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let games = guild
+            .select_games::<ScrimOrMatch>(None, identity)
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let events = stream::iter(&games)
+            .then(|game| game.calendar_event(ctx, &guild))
+            .try_collect()
+            .await?;
+
+        let calendar = Calendar { events };
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().new_attachment(CreateAttachment::bytes(
+                    calendar.to_string().into_bytes(),
+                    "schedule.ics",
+                )),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -8,7 +8,6 @@ use crate::{
         Map,
         game::{GameDetails, ScrimOrMatch},
     },
-    error::BotError,
     serveme::EditReservationRequest,
     utils::success_embed,
 };
@@ -39,12 +38,7 @@ impl ChangelevelCommand {
         let game = if let Some(game) = self.game {
             guild.get_game::<ScrimOrMatch>(&tx, game).await?
         } else {
-            guild
-                .select_closest_active_games::<ScrimOrMatch>()
-                .await?
-                .one(&tx)
-                .await?
-                .ok_or(BotError::NoActiveGames)?
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
         };
 
         let reservation_id = game.server.reservation_id()?;
@@ -59,7 +53,7 @@ impl ChangelevelCommand {
             server_config_id,
             ..Default::default()
         }
-        .send(guild.serveme_api_key()?, reservation_id)
+        .send(&guild.serveme_account()?, reservation_id)
         .await?;
 
         interaction
@@ -87,15 +81,10 @@ impl ChangelevelCommandAutocomplete {
                 let game = if let Some(game) = game.into_value().flatten() {
                     guild.get_game::<ScrimOrMatch>(&tx, game).await?
                 } else {
-                    guild
-                        .select_closest_active_games::<ScrimOrMatch>()
-                        .await?
-                        .one(&tx)
-                        .await?
-                        .ok_or(BotError::NoActiveGames)?
+                    guild.closest_active_game::<ScrimOrMatch>(&tx).await?
                 };
 
-                game.autocomplete_maps(ctx, interaction, guild.serveme_api_key()?, &map)
+                game.autocomplete_maps(ctx, interaction, &guild.serveme_account()?, &map)
                     .await
             }
             Self::Game { game, .. } => {
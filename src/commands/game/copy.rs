@@ -0,0 +1,98 @@
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{Game, GameServer, ScrimOrMatch},
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct CopyCommand {
+    /// The game to copy.
+    #[command(autocomplete)]
+    game: OffsetDateTime,
+
+    /// The date/time to schedule the copy for.
+    #[command(autocomplete)]
+    date_time: OffsetDateTime,
+}
+
+impl CopyCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = guild.get_game::<ScrimOrMatch>(&tx, self.game).await?;
+
+        guild.ensure_time_open(&tx, self.date_time).await?;
+
+        guild.validate_schedule_time(self.date_time)?;
+
+        let mut game = Game {
+            timestamp: self.date_time,
+            server: GameServer::Undecided,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
+            ..game
+        };
+
+        if let ScrimOrMatch::Scrim(scrim) = &mut game.details {
+            scrim.result = None;
+        }
+
+        let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
+
+        let embed = game.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(vec![success_embed("Game copied."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl CopyCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        match self {
+            Self::Game { game, .. } => {
+                guild
+                    .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+                    .await
+            }
+            Self::DateTime { date_time, .. } => {
+                guild
+                    .autocomplete_times(ctx, interaction, tx, &date_time)
+                    .await
+            }
+        }
+    }
+}
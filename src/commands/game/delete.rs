@@ -1,14 +1,8 @@
-use sea_orm::EntityTrait;
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
 use serenity_commands::SubCommand;
 use time::OffsetDateTime;
 
-use crate::{
-    Bot, BotResult,
-    entities::game::{self, Game, ScrimOrMatch},
-    error::BotError,
-    utils::success_embed,
-};
+use crate::{Bot, BotResult, entities::game::ScrimOrMatch, utils::success_embed};
 
 #[derive(Clone, Debug, SubCommand)]
 pub struct DeleteCommand {
@@ -29,20 +23,14 @@ impl DeleteCommand {
 
         let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
-        let mut res = game::Entity::delete_by_id((guild.id, self.game))
-            .exec_with_returning(&tx)
-            .await?;
-
-        let Some(game) = res.pop() else {
-            return Err(BotError::GameNotFound);
-        };
+        let game = guild.delete_game(ctx, &tx, self.game).await?;
 
-        let embed = Game::try_from(game)?.embed(&guild).await?;
-
-        guild.refresh_schedule(ctx, &tx).await?;
+        let embed = game.embed(&guild).await?;
 
         tx.commit().await?;
 
+        game.delete_discord_event(ctx).await;
+
         interaction
             .edit_response(
                 &ctx,
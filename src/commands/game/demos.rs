@@ -0,0 +1,93 @@
+use serenity::all::{CommandInteraction, Context, CreateEmbed, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult, demostf::Demo, entities::game::ScrimOrMatch, error::BotError,
+    utils::time_string,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct DemosCommand {
+    /// The game to list demos.tf uploads of. If not provided, the most recent
+    /// game will be used.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+}
+
+impl DemosCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = if let Some(game) = self.game {
+            guild.get_game::<ScrimOrMatch>(&tx, game).await?
+        } else {
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+        };
+
+        tx.commit().await?;
+
+        if !game.server.is_hosted() {
+            return Err(BotError::GameNotHosted);
+        }
+
+        let reservation = game.get_reservation(&guild.serveme_account()?).await?;
+
+        let demos = Demo::for_reservation(&reservation).await?;
+
+        let description = if demos.is_empty() {
+            "No demos have been uploaded yet.".to_owned()
+        } else {
+            demos
+                .iter()
+                .map(|demo| {
+                    format!(
+                        "- **{}:** [{}]({}) - {} {}:{} {}",
+                        time_string(guild.to_local(demo.time).time()),
+                        demo.map,
+                        demo.url(),
+                        demo.blue,
+                        demo.blue_score,
+                        demo.red_score,
+                        demo.red,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let embed = CreateEmbed::new()
+            .title("🎥 Demos")
+            .description(description);
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl DemosCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Game { game } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+            .await
+    }
+}
@@ -0,0 +1,57 @@
+use std::convert::identity;
+
+use serenity::{
+    all::{CommandInteraction, Context, CreateAttachment, EditInteractionResponse},
+    futures::{StreamExt, TryStreamExt, stream},
+};
+use serenity_commands::SubCommandGroup;
+
+use crate::{Bot, BotResult, entities::game::ScrimOrMatch};
+
+#[derive(Clone, Debug, SubCommandGroup)]
+pub enum ExportCommand {
+    /// Export the schedule as a JSON file.
+    Json,
+}
+
+impl ExportCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let games = guild
+            .select_games::<ScrimOrMatch>(None, identity)
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        match self {
+            Self::Json => {
+                let games = stream::iter(&games)
+                    .then(|game| game.export(&guild))
+                    .try_collect::<Vec<_>>()
+                    .await?;
+
+                let json = serde_json::to_vec_pretty(&games)
+                    .expect("exported games should always serialize");
+
+                interaction
+                    .edit_response(
+                        &ctx,
+                        EditInteractionResponse::new()
+                            .new_attachment(CreateAttachment::bytes(json, "schedule.json")),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+
+use crate::{Bot, BotResult, components::GamesPageButton};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct ListCommand;
+
+impl ListCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let (embed, next) = guild.games_page(ctx, &tx, None).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embed(embed)
+                    .components(GamesPageButton::components(next)),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use serenity::all::{CommandInteraction, Context, CreateEmbed, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult, entities::game::ScrimOrMatch, error::BotError, logstf::Log, utils::time_string,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct LogsCommand {
+    /// The game to list logs.tf uploads of. If not provided, the most recent
+    /// game will be used.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+}
+
+impl LogsCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = if let Some(game) = self.game {
+            guild.get_game::<ScrimOrMatch>(&tx, game).await?
+        } else {
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+        };
+
+        tx.commit().await?;
+
+        if !game.server.is_hosted() {
+            return Err(BotError::GameNotHosted);
+        }
+
+        let reservation = game.get_reservation(&guild.serveme_account()?).await?;
+
+        let logs = Log::for_reservation(&reservation).await?;
+
+        let description = if logs.is_empty() {
+            "No logs have been uploaded yet.".to_owned()
+        } else {
+            logs.iter()
+                .map(|log| {
+                    format!(
+                        "- **{}:** [{}]({}) - {}",
+                        time_string(guild.to_local(log.date).time()),
+                        log.map,
+                        log.url(),
+                        log.title,
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let embed = CreateEmbed::new().title("🪵 Logs").description(description);
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl LogsCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Game { game } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+            .await
+    }
+}
@@ -1,36 +1,130 @@
+mod calendar;
 mod changelevel;
+mod copy;
 mod delete;
+mod demos;
+mod export;
+mod list;
+mod logs;
+mod r#move;
+mod next;
+mod note;
+mod practice_config;
 mod rcon;
+mod rcon_macro;
+mod search;
 mod show;
+mod stv;
+mod whitelist;
 
 use serenity::all::{CommandInteraction, Context};
 use serenity_commands::Command;
 
 use self::{
-    changelevel::ChangelevelCommand, delete::DeleteCommand, rcon::RconCommand, show::ShowCommand,
+    calendar::CalendarCommand, changelevel::ChangelevelCommand, copy::CopyCommand,
+    delete::DeleteCommand, demos::DemosCommand, export::ExportCommand, list::ListCommand,
+    logs::LogsCommand, r#move::MoveCommand, next::NextCommand, note::NoteCommand,
+    practice_config::PracticeConfigCommand, rcon::RconCommand, rcon_macro::RconMacroCommand,
+    search::SearchCommand, show::ShowCommand, stv::StvCommand, whitelist::WhitelistCommand,
 };
 use crate::{Bot, BotResult};
 
 #[derive(Debug, Command)]
 pub enum GameCommand {
+    /// List upcoming games.
+    List(ListCommand),
+
+    /// Show the soonest upcoming game.
+    Next(NextCommand),
+
     /// Show the details of a game.
     #[command(autocomplete)]
     Show(ShowCommand),
 
+    /// Copy a game to a new date/time.
+    #[command(autocomplete)]
+    Copy(CopyCommand),
+
+    /// Move a game to a new date/time, keeping its server.
+    #[command(autocomplete)]
+    Move(MoveCommand),
+
     /// Delete a game from the schedule.
     #[command(autocomplete)]
     Delete(DeleteCommand),
 
+    /// Show the STV details of a hosted game.
+    #[command(autocomplete)]
+    Stv(StvCommand),
+
+    /// List the demos.tf uploads of a hosted game.
+    #[command(autocomplete)]
+    Demos(DemosCommand),
+
+    /// List the logs.tf uploads of a hosted game.
+    #[command(autocomplete)]
+    Logs(LogsCommand),
+
     /// Run a command on the game server.
     #[command(autocomplete)]
     Rcon(RconCommand),
 
+    /// Manage saved RCON commands.
+    #[command(autocomplete)]
+    RconMacro(RconMacroCommand),
+
     /// Change the map of a game.
     #[command(autocomplete)]
     Changelevel(ChangelevelCommand),
+
+    /// Apply a whitelist on the game server.
+    #[command(autocomplete)]
+    Whitelist(WhitelistCommand),
+
+    /// Set or clear a note on a game.
+    #[command(autocomplete)]
+    Note(NoteCommand),
+
+    /// Switch a game's server to a practice config without changing the game.
+    #[command(autocomplete)]
+    PracticeConfig(PracticeConfigCommand),
+
+    /// Search past and upcoming games by opponent or map.
+    #[command(autocomplete)]
+    Search(SearchCommand),
+
+    /// Export the schedule as an iCalendar file.
+    Calendar(CalendarCommand),
+
+    /// Export the schedule for use in other tools.
+    Export(ExportCommand),
 }
 
 impl GameCommand {
+    /// Whether the command changes the schedule or a reservation.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Copy(_)
+            | Self::Move(_)
+            | Self::Delete(_)
+            | Self::Note(_)
+            | Self::PracticeConfig(_)
+            | Self::Rcon(_) => true,
+            Self::RconMacro(cmd) => cmd.is_mutating(),
+            Self::List(_)
+            | Self::Next(_)
+            | Self::Show(_)
+            | Self::Stv(_)
+            | Self::Demos(_)
+            | Self::Logs(_)
+            | Self::Changelevel(_)
+            | Self::Whitelist(_)
+            | Self::Search(_)
+            | Self::Calendar(_)
+            | Self::Export(_) => false,
+        }
+    }
+
     pub async fn run(
         self,
         bot: &Bot,
@@ -38,10 +132,24 @@ impl GameCommand {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::List(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Next(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Show(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Copy(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Move(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Delete(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Stv(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Demos(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Logs(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Rcon(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::RconMacro(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Changelevel(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Whitelist(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Note(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::PracticeConfig(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Search(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Calendar(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Export(cmd) => cmd.run(bot, ctx, interaction).await,
         }
     }
 }
@@ -55,9 +163,19 @@ impl GameCommandAutocomplete {
     ) -> BotResult {
         match self {
             Self::Show(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Copy(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Move(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Delete(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Stv(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Demos(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Logs(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Rcon(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::RconMacro(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Changelevel(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Whitelist(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Note(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::PracticeConfig(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Search(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
         }
     }
 }
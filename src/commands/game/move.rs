@@ -0,0 +1,138 @@
+use std::iter;
+
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, sea_query::Expr};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{self, GameDetails, ScrimOrMatch},
+    error::BotError,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct MoveCommand {
+    /// The game to move.
+    #[command(autocomplete)]
+    game: OffsetDateTime,
+
+    /// The new date/time of the game.
+    #[command(autocomplete)]
+    date_time: OffsetDateTime,
+}
+
+impl MoveCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut game = guild.get_game::<ScrimOrMatch>(&tx, self.game).await?;
+
+        guild.ensure_time_open(&tx, self.date_time).await?;
+
+        guild.validate_schedule_time(self.date_time)?;
+
+        game.timestamp = self.date_time;
+
+        let overlap_warning = if game.server.is_hosted() {
+            guild
+                .check_overlap(&tx, self.date_time, game.details.kind(), Some(self.game))
+                .await?
+        } else {
+            None
+        };
+
+        // the timestamp is part of the primary key, so it can't be changed
+        // through `ActiveModel::update`.
+        let res = game::Entity::update_many()
+            .col_expr(game::Column::Timestamp, Expr::value(self.date_time))
+            .col_expr(game::Column::Version, Expr::value(game.version + 1))
+            .filter(game::Column::GuildId.eq(guild.id))
+            .filter(game::Column::Timestamp.eq(self.game))
+            .filter(game::Column::Version.eq(game.version))
+            .exec(&tx)
+            .await?;
+
+        if res.rows_affected == 0 {
+            return Err(BotError::ConcurrentModification);
+        }
+
+        // the row is only moved once the transaction commits, so the
+        // reservations are put back if anything after moving them fails.
+        let moved = if game.server.is_hosted() {
+            let account = guild.serveme_account()?;
+            let previous = game.move_reservations(&account).await?;
+
+            Some((account, previous))
+        } else {
+            None
+        };
+
+        let committed = async {
+            let embed = game.embed(&guild).await?;
+
+            guild.refresh_schedule(ctx, &tx).await?;
+
+            tx.commit().await?;
+
+            BotResult::Ok(embed)
+        }
+        .await;
+
+        if committed.is_err()
+            && let Some((account, previous)) = &moved
+        {
+            game.restore_reservations(account, previous).await;
+        }
+
+        let embed = committed?;
+
+        game.sync_discord_event(ctx).await;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(
+                    iter::once(success_embed("Game moved."))
+                        .chain(overlap_warning)
+                        .chain(iter::once(embed))
+                        .collect(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl MoveCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        match self {
+            Self::Game { game, .. } => {
+                guild
+                    .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+                    .await
+            }
+            Self::DateTime { date_time, .. } => {
+                guild
+                    .autocomplete_times(ctx, interaction, tx, &date_time)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use sea_orm::QuerySelect;
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{self, Game},
+    utils::embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct NextCommand;
+
+impl NextCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = game::Entity::find_upcoming(guild.id)
+            .limit(1)
+            .into_partial_model::<Game>()
+            .one(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let embed = match game {
+            Some(game) => game.embed(&guild).await?,
+            None => embed("📭 No Upcoming Games")
+                .description("There are no upcoming games on the schedule."),
+        };
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{self, Game, ScrimOrMatch},
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct NoteCommand {
+    /// The game to annotate.
+    #[command(autocomplete)]
+    game: OffsetDateTime,
+
+    /// The note to attach to the game. If not provided, the existing note will
+    /// be cleared.
+    note: Option<String>,
+}
+
+impl NoteCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut game = guild.get_game::<ScrimOrMatch>(&tx, self.game).await?;
+
+        game.note = self
+            .note
+            .map(|note| note.trim().to_owned())
+            .filter(|note| !note.is_empty());
+
+        let message = if game.note.is_some() {
+            "Note updated."
+        } else {
+            "Note cleared."
+        };
+
+        let mut active_model = game.into_active_model();
+        active_model.reset(game::Column::Note);
+
+        let game = Game::<ScrimOrMatch>::try_from(active_model.update_versioned(&tx).await?)?;
+
+        let embed = game.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(vec![success_embed(message), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl NoteCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Game { game, .. } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+            .await
+    }
+}
@@ -0,0 +1,119 @@
+use serenity::all::{
+    AutocompleteChoice, CommandInteraction, Context, CreateAutocompleteResponse,
+    CreateInteractionResponse, EditInteractionResponse,
+};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::{ServerConfig, game::ScrimOrMatch},
+    serveme::EditReservationRequest,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct PracticeConfigCommand {
+    /// The server config to switch to.
+    #[command(autocomplete)]
+    config: u32,
+
+    /// The game to change the config of. If not provided, the most recent game
+    /// will be used.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+}
+
+impl PracticeConfigCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = if let Some(game) = self.game {
+            guild.get_game::<ScrimOrMatch>(&tx, game).await?
+        } else {
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+        };
+
+        tx.commit().await?;
+
+        EditReservationRequest {
+            server_config_id: Some(self.config),
+            ..Default::default()
+        }
+        .send(&guild.serveme_account()?, game.server.reservation_id()?)
+        .await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embed(success_embed("Server config changed.")),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl PracticeConfigCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Config { config, .. } => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                let query = config.trim().to_lowercase();
+
+                let guild_config = guild
+                    .practice_config_id
+                    .and_then(|id| u32::try_from(id).ok())
+                    .map(|id| AutocompleteChoice::new(format!("Team practice config ({id})"), id));
+
+                let choices = guild_config
+                    .into_iter()
+                    .chain(
+                        ServerConfig::ALL
+                            .iter()
+                            .filter(|c| c.name.to_lowercase().contains(&query))
+                            .map(|c| AutocompleteChoice::new(c.name, c.id)),
+                    )
+                    .take(25)
+                    .collect();
+
+                interaction
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Autocomplete(
+                            CreateAutocompleteResponse::new().set_choices(choices),
+                        ),
+                    )
+                    .await?;
+
+                Ok(())
+            }
+            Self::Game { game, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_games::<ScrimOrMatch>(
+                        ctx,
+                        interaction,
+                        tx,
+                        Some(guild.select_closest_active_games::<ScrimOrMatch>().await?),
+                        &game,
+                    )
+                    .await
+            }
+        }
+    }
+}
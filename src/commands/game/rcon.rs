@@ -1,22 +1,38 @@
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, ColumnTrait, EntityTrait, QueryFilter};
 use serenity::all::{CommandInteraction, Context, CreateAttachment, EditInteractionResponse};
 use serenity_commands::SubCommand;
+use time::OffsetDateTime;
 
 use crate::{
     Bot, BotResult,
-    entities::{ReservationId, game::ScrimOrMatch},
+    components::RconPageButton,
+    entities::{
+        ReservationId,
+        game::{self, ScrimOrMatch},
+        rcon_commands_log, rcon_macro,
+    },
     error::BotError,
     serveme::GetReservationRequest,
 };
 
 #[derive(Clone, Debug, SubCommand)]
 pub struct RconCommand {
-    /// The command to run.
+    /// The command to run. `{map}` is replaced with the server's first map.
+    #[command(autocomplete)]
     command: String,
 
-    /// The reservation to run the command on. If not provided, the most recent
-    /// game will be used.
+    /// The reservation to run the command on. If neither this nor a game is
+    /// provided, the most recent game will be used.
     #[command(autocomplete)]
     reservation: Option<ReservationId>,
+
+    /// The game to run the command on. Ignored if a reservation is provided.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+
+    /// Whether to show long output as pages instead of a file. Defaults to
+    /// false.
+    paginate: Option<bool>,
 }
 
 impl RconCommand {
@@ -30,29 +46,84 @@ impl RconCommand {
 
         let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
-        let reservation_id = if let Some(reservation_id) = self.reservation {
-            reservation_id
-        } else {
-            guild
-                .select_closest_active_games::<ScrimOrMatch>()
-                .await?
+        let (reservation_id, game_timestamp) = if let Some(reservation_id) = self.reservation {
+            let game = game::Entity::find()
+                .filter(game::Column::GuildId.eq(guild.id))
+                .filter(game::Column::ReservationId.eq(reservation_id))
                 .one(&tx)
-                .await?
-                .ok_or(BotError::NoActiveGames)?
-                .server
-                .reservation_id()?
+                .await?;
+
+            (reservation_id, game.map(|game| game.timestamp))
+        } else {
+            let game = if let Some(game) = self.game {
+                guild.get_game::<ScrimOrMatch>(&tx, game).await?
+            } else {
+                guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+            };
+
+            (game.server.reservation_id()?, Some(game.timestamp))
         };
 
         let reservation =
-            GetReservationRequest::send(guild.serveme_api_key()?, reservation_id).await?;
+            GetReservationRequest::send(&guild.serveme_account()?, reservation_id).await?;
+
+        let command = self.command.replace(
+            rcon_macro::MAP_PLACEHOLDER,
+            reservation
+                .first_map
+                .as_ref()
+                .map_or("", |map| map.as_str()),
+        );
 
-        let resp = reservation.rcon(&self.command).await?;
+        let resp = match reservation.rcon(&command).await {
+            Ok(resp) => resp,
+            Err(BotError::RconTimeout) => {
+                interaction
+                    .edit_response(
+                        &ctx,
+                        EditInteractionResponse::new().content(format!(
+                            "{}\nCheck `/game show` to see whether the server is ready.",
+                            BotError::RconTimeout
+                        )),
+                    )
+                    .await?;
+
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        // commands run on reservations that aren't scheduled games have nowhere
+        // to be shown, so they aren't logged.
+        if let Some(game_timestamp) = game_timestamp {
+            rcon_commands_log::ActiveModel {
+                guild_id: Set(guild.id),
+                game_timestamp: Set(game_timestamp),
+                command: Set(command),
+                response_truncated: Set(resp
+                    .chars()
+                    .take(rcon_commands_log::MAX_RESPONSE_CHARS)
+                    .collect()),
+                executed_by: Set(interaction.user.id.into()),
+                ..Default::default()
+            }
+            .insert(&tx)
+            .await?;
+
+            tx.commit().await?;
+        }
+
+        let edit = if resp.len() + "```\n\n```".len() <= 2000 {
+            EditInteractionResponse::new().content(format!("```\n{resp}\n```"))
+        } else if self.paginate.unwrap_or(false) {
+            let (content, components) = RconPageButton::store(interaction.id, &resp).await;
 
-        let edit = if resp.len() + "```\n\n```".len() > 2000 {
             EditInteractionResponse::new()
-                .new_attachment(CreateAttachment::bytes(resp.as_bytes(), "rcon.log"))
+                .content(content)
+                .components(components)
         } else {
-            EditInteractionResponse::new().content(format!("```\n{resp}\n```"))
+            EditInteractionResponse::new()
+                .new_attachment(CreateAttachment::bytes(resp.as_bytes(), "rcon.log"))
         };
 
         interaction.edit_response(&ctx, edit).await?;
@@ -69,6 +140,13 @@ impl RconCommandAutocomplete {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::Command { command, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_rcon_commands(ctx, interaction, tx, &command)
+                    .await
+            }
             Self::Reservation { reservation, .. } => {
                 let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
@@ -82,6 +160,13 @@ impl RconCommandAutocomplete {
                     )
                     .await
             }
+            Self::Game { game, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,133 @@
+use sea_orm::{ActiveValue::Set, EntityTrait, ModelTrait, QueryOrder, sea_query::OnConflict};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::{SubCommand, SubCommandGroup};
+
+use crate::{
+    Bot, BotResult,
+    entities::rcon_macro,
+    utils::{embed, success_embed},
+};
+
+#[derive(Debug, SubCommandGroup)]
+pub enum RconMacroCommand {
+    /// Save an RCON command under a name.
+    Add(RconMacroAddCommand),
+
+    /// Delete a saved RCON macro.
+    #[command(autocomplete)]
+    Remove(RconMacroRemoveCommand),
+
+    /// List saved RCON macros.
+    List(RconMacroListCommand),
+}
+
+#[derive(Debug, SubCommand)]
+pub struct RconMacroAddCommand {
+    /// The name of the macro. Saving over an existing name replaces it.
+    name: String,
+
+    /// The command to run. `{map}` is replaced with the server's first map.
+    command: String,
+}
+
+#[derive(Debug, SubCommand)]
+pub struct RconMacroRemoveCommand {
+    /// The macro to delete.
+    #[command(autocomplete)]
+    name: String,
+}
+
+#[derive(Debug, SubCommand)]
+pub struct RconMacroListCommand;
+
+impl RconMacroCommand {
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Add(_) | Self::Remove(_) => true,
+            Self::List(_) => false,
+        }
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let embed = match self {
+            Self::Add(cmd) => {
+                rcon_macro::Entity::insert(rcon_macro::ActiveModel {
+                    guild_id: Set(guild.id),
+                    name: Set(cmd.name.clone()),
+                    command: Set(cmd.command),
+                })
+                .on_conflict(
+                    OnConflict::columns([rcon_macro::Column::GuildId, rcon_macro::Column::Name])
+                        .update_column(rcon_macro::Column::Command)
+                        .to_owned(),
+                )
+                .exec(&tx)
+                .await?;
+
+                success_embed(format!("Macro `{}` saved.", cmd.name))
+            }
+            Self::Remove(cmd) => {
+                guild
+                    .get_rcon_macro(&tx, &cmd.name)
+                    .await?
+                    .delete(&tx)
+                    .await?;
+
+                success_embed(format!("Macro `{}` deleted.", cmd.name))
+            }
+            Self::List(_) => {
+                let macros = guild
+                    .find_related(rcon_macro::Entity)
+                    .order_by_asc(rcon_macro::Column::Name)
+                    .all(&tx)
+                    .await?;
+
+                let description = if macros.is_empty() {
+                    "No macros saved.".to_owned()
+                } else {
+                    macros
+                        .iter()
+                        .map(|m| format!("**{}:** `{}`", m.name, m.command))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+
+                embed("⭐ RCON Macros").description(description)
+            }
+        };
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl RconMacroCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Remove(RconMacroRemoveCommandAutocomplete::Name { name }) = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_rcon_macros(ctx, interaction, tx, &name)
+            .await
+    }
+}
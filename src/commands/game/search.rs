@@ -0,0 +1,109 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+
+use crate::{
+    Bot, BotResult,
+    entities::{OpponentUserId, game::GameDetails},
+    error::BotError,
+    utils::embed,
+};
+
+const MAX_RESULTS: usize = 25;
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct SearchCommand {
+    /// The opposing team's contact to search for.
+    #[command(autocomplete)]
+    opponent: Option<OpponentUserId>,
+
+    /// Part of a map name to search for.
+    map: Option<String>,
+}
+
+impl SearchCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        if self.opponent.is_none() && self.map.is_none() {
+            return Err(BotError::NoSearchFilter);
+        }
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut games = guild
+            .search_games(self.opponent, self.map.as_deref(), MAX_RESULTS as u64 + 1)
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let more = games.len() > MAX_RESULTS;
+        games.truncate(MAX_RESULTS);
+
+        let mut lines = Vec::with_capacity(games.len());
+
+        for game in &games {
+            let vs = game
+                .details
+                .opponent_string(ctx, guild.rgl_team_id)
+                .await?
+                .map(|opponent| format!(" vs. {opponent}"))
+                .unwrap_or_default();
+
+            let maps = game
+                .details
+                .maps()
+                .await?
+                .list(false)
+                .map(|maps| format!(" - {maps}"))
+                .unwrap_or_default();
+
+            lines.push(format!(
+                "{}{vs}{maps}",
+                game.details.title(&guild, game.timestamp)
+            ));
+        }
+
+        if lines.is_empty() {
+            lines.push("No games found.".to_owned());
+        }
+
+        if more {
+            lines.push(format!(
+                "\n*More results exist; only the {MAX_RESULTS} most recent are shown.*"
+            ));
+        }
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embed(embed("🔎 Search Results").description(lines.join("\n"))),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl SearchCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Opponent { opponent, .. } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_opponents(ctx, interaction, tx, &opponent)
+            .await
+    }
+}
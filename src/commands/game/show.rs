@@ -1,8 +1,16 @@
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity::all::{
+    CommandInteraction, Context, CreateActionRow, EditInteractionResponse, Mentionable, Permissions,
+};
 use serenity_commands::SubCommand;
 use time::OffsetDateTime;
 
-use crate::{Bot, BotResult, entities::game::ScrimOrMatch};
+use crate::{
+    Bot, BotResult,
+    components::{DeleteGameButton, GameRconButton},
+    entities::{game::ScrimOrMatch, rcon_commands_log},
+};
+
+const RECENT_RCON_COMMANDS: u64 = 5;
 
 #[derive(Clone, Debug, SubCommand)]
 pub struct ShowCommand {
@@ -23,12 +31,67 @@ impl ShowCommand {
 
         let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
-        let embed = guild.get_game(&tx, self.game).await?.embed(&guild).await?;
+        let game = guild.get_game(&tx, self.game).await?;
+
+        let mut embed = game.embed(&guild).await?;
+
+        // rcon details and commands can contain passwords, so only admins get to
+        // see them.
+        let can_manage_guild = interaction
+            .member
+            .as_ref()
+            .and_then(|member| member.permissions)
+            .is_some_and(Permissions::manage_guild);
+
+        if can_manage_guild {
+            if let Some(rcon) = game.rcon_block(&guild).await {
+                embed = embed.field("RCON", rcon, false);
+            }
+
+            let commands = rcon_commands_log::Entity::find_recent(
+                guild.id,
+                game.timestamp,
+                RECENT_RCON_COMMANDS,
+            )
+            .all(&tx)
+            .await?;
+
+            if !commands.is_empty() {
+                embed = embed.field(
+                    "Recent RCON Commands",
+                    commands
+                        .iter()
+                        .map(|c| {
+                            format!(
+                                "`{}` - {}",
+                                c.command.chars().take(100).collect::<String>(),
+                                c.executed_by.mention()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    false,
+                );
+            }
+        }
 
         tx.commit().await?;
 
+        let mut buttons = Vec::new();
+
+        if can_manage_guild && let Ok(reservation_id) = game.server.reservation_id() {
+            buttons.push(GameRconButton::create(guild.id.into(), reservation_id));
+        }
+
+        buttons.push(DeleteGameButton::create(&game));
+
         interaction
-            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embed(embed)
+                    .components(vec![CreateActionRow::Buttons(buttons)]),
+            )
             .await?;
 
         Ok(())
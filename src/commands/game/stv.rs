@@ -0,0 +1,150 @@
+use serenity::all::{CommandInteraction, Context, CreateEmbed, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult, entities::game::ScrimOrMatch, error::BotError, serveme::ReservationResponse,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct StvCommand {
+    /// The game to get STV details of. If not provided, the most recent game
+    /// will be used.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+
+    /// Whether to post the details publicly. The STV password will be masked.
+    public: Option<bool>,
+}
+
+impl StvCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let public = self.public.unwrap_or(false);
+
+        if public {
+            interaction.defer(ctx).await?;
+        } else {
+            interaction.defer_ephemeral(ctx).await?;
+        }
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = if let Some(game) = self.game {
+            guild.get_game::<ScrimOrMatch>(&tx, game).await?
+        } else {
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+        };
+
+        tx.commit().await?;
+
+        if !game.server.is_hosted() {
+            return Err(BotError::GameNotHosted);
+        }
+
+        let reservation = game.get_reservation(&guild.serveme_account()?).await?;
+
+        let embed = stv_embed(&reservation, public);
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// The STV details of a reservation. Public embeds leave the STV password out
+/// entirely, including from the connect command.
+fn stv_embed(reservation: &ReservationResponse, public: bool) -> CreateEmbed {
+    let stv_connect_info = reservation.stv_connect_info();
+
+    let (connect, password) = if public {
+        (
+            format!("```\nconnect {}\n```", stv_connect_info.ip_and_port),
+            "`********`".to_owned(),
+        )
+    } else {
+        (
+            stv_connect_info.code_block(),
+            format!("`{}`", stv_connect_info.password),
+        )
+    };
+
+    CreateEmbed::new()
+        .title("📺 STV")
+        .description(connect)
+        .fields([
+            ("Address", format!("`{}`", reservation.server.ip), true),
+            ("Port", format!("`{}`", reservation.tv_port), true),
+            ("Password", password, true),
+        ])
+}
+
+impl StvCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Game { game, .. } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<ScrimOrMatch>(ctx, interaction, tx, None, &game)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{
+        entities::ReservationId,
+        serveme::{ReservationStatus, Server},
+    };
+
+    fn reservation() -> ReservationResponse {
+        ReservationResponse {
+            id: ReservationId(1),
+            status: ReservationStatus::Ready,
+            starts_at: datetime!(2024-10-16 20:45 UTC),
+            ends_at: datetime!(2024-10-16 22:15 UTC),
+            password: "server-password".to_owned(),
+            rcon: "rcon-password".to_owned(),
+            first_map: None,
+            tv_password: "tv-password".to_owned(),
+            tv_port: 27020,
+            server_config_id: None,
+            server: Server {
+                id: 1,
+                name: "Chicago #1".to_owned(),
+                ip: "chi.serveme.tf".to_owned(),
+                ip_and_port: "chi.serveme.tf:27015".to_owned(),
+            },
+        }
+    }
+
+    #[test]
+    fn public_embed_hides_stv_password() {
+        let embed = serde_json::to_string(&stv_embed(&reservation(), true)).unwrap();
+
+        assert!(!embed.contains("tv-password"), "{embed}");
+        assert!(embed.contains("connect chi.serveme.tf:27020"), "{embed}");
+    }
+
+    #[test]
+    fn private_embed_shows_stv_password() {
+        let embed = serde_json::to_string(&stv_embed(&reservation(), false)).unwrap();
+
+        assert!(embed.contains("tv-password"), "{embed}");
+    }
+}
@@ -0,0 +1,107 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{GameDetails, ScrimOrMatch},
+    error::BotError,
+    serveme::GetReservationRequest,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct WhitelistCommand {
+    /// A whitelist.tf ID to apply. If not provided, the RGL whitelist for the
+    /// game's format will be used.
+    whitelist_id: Option<String>,
+
+    /// The game to apply the whitelist to. If not provided, the most recent
+    /// game will be used.
+    #[command(autocomplete)]
+    game: Option<OffsetDateTime>,
+}
+
+impl WhitelistCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = if let Some(game) = self.game {
+            guild.get_game::<ScrimOrMatch>(&tx, game).await?
+        } else {
+            guild.closest_active_game::<ScrimOrMatch>(&tx).await?
+        };
+
+        tx.commit().await?;
+
+        let command = if let Some(whitelist_id) = self.whitelist_id {
+            let whitelist_id = whitelist_id.trim();
+
+            if whitelist_id.is_empty()
+                || !whitelist_id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(BotError::InvalidWhitelistId);
+            }
+
+            format!("tftrue_whitelist_id {whitelist_id}")
+        } else {
+            format!(
+                "mp_tournament_whitelist {}",
+                game.details.game_format().await?.rgl_whitelist()
+            )
+        };
+
+        let reservation =
+            GetReservationRequest::send(&guild.serveme_account()?, game.server.reservation_id()?)
+                .await?;
+
+        if !reservation.status.is_ready() {
+            return Err(BotError::ServerNotReady);
+        }
+
+        reservation.rcon(&command).await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embed(success_embed(format!(
+                    "Whitelist applied with `{command}`."
+                ))),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl WhitelistCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Game { game, .. } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<ScrimOrMatch>(
+                ctx,
+                interaction,
+                tx,
+                Some(guild.select_closest_active_games::<ScrimOrMatch>().await?),
+                &game,
+            )
+            .await
+    }
+}
@@ -0,0 +1,88 @@
+use sea_orm::{ActiveModelTrait, ColumnTrait, IntoActiveModel, QueryFilter};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{self, Game, Match, ScrimOrMatch},
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct AddReservationCommand {
+    /// The hosted match to add a backup reservation to.
+    #[command(autocomplete)]
+    match_: OffsetDateTime,
+}
+
+impl AddReservationCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut match_ = guild.get_game::<Match>(&tx, self.match_).await?;
+
+        let reservation = match_
+            .create_backup_reservation(&guild.serveme_account()?, None)
+            .await?;
+
+        let mut active_model = match_.into_active_model();
+        active_model.reset(game::Column::BackupReservationId);
+
+        let match_ = Game::<ScrimOrMatch>::try_from(active_model.update_versioned(&tx).await?)?;
+
+        let embed = match_.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(vec![
+                    success_embed(format!(
+                        "Backup reservation added.\n{}",
+                        reservation.connect_info().code_block()
+                    )),
+                    embed,
+                ]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl AddReservationCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Match { match_ } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<Match>(
+                ctx,
+                interaction,
+                tx,
+                Some(guild.select_games::<Match>(None, |s| {
+                    s.filter(game::Column::ReservationId.is_not_null())
+                        .filter(game::Column::BackupReservationId.is_null())
+                })),
+                &match_,
+            )
+            .await
+    }
+}
@@ -1,5 +1,5 @@
 use paste::paste;
-use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use sea_orm::IntoActiveModel;
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
 use serenity_commands::{SubCommand, SubCommandGroup};
 use time::OffsetDateTime;
@@ -74,7 +74,7 @@ macro_rules! edit_command {
                             }
                         )*
                     }
-                    .update(&tx)
+                    .update_versioned(&tx)
                     .await?;
 
                     let embed = Game::try_from(game)?.embed(&guild).await?;
@@ -126,14 +126,17 @@ impl EditReservationIdCommand {
         }
 
         if match_.server.is_hosted() {
-            let api_key = guild.serveme_api_key()?;
+            let account = &guild.serveme_account()?;
 
-            match_.edit_reservation(api_key).await?;
+            match_.edit_reservation(account).await?;
         }
 
+        match_.credentials = None;
+
+        match_.backup_reservation_id = None;
+
         let mut active_model = match_.into_active_model();
-        active_model.reset(game::Column::ReservationId);
-        active_model.reset(game::Column::ConnectInfo);
+        active_model.reset_server();
 
         Ok(active_model)
     }
@@ -152,8 +155,12 @@ impl EditConnectInfoCommand {
             match_.server = GameServer::Undecided;
         }
 
+        match_.credentials = None;
+
+        match_.backup_reservation_id = None;
+
         let mut active_model = match_.into_active_model();
-        active_model.reset(game::Column::ReservationId);
+        active_model.reset_server();
 
         Ok(active_model)
     }
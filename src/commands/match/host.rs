@@ -1,3 +1,5 @@
+use std::iter;
+
 use sea_orm::{ActiveModelTrait, IntoActiveModel};
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
 use serenity_commands::SubCommand;
@@ -5,9 +7,10 @@ use serenity_commands::SubCommand;
 use crate::{
     Bot, BotResult,
     entities::{
-        ReservationId,
-        game::{Game, GameServer, Match},
+        MapVetoes, ReservationId,
+        game::{Game, GameKind, GameServer, Match, ReservationOptions},
     },
+    error::BotError,
     rgl::{RglMatch, RglMatchId},
     utils::success_embed,
 };
@@ -20,6 +23,19 @@ pub struct HostCommand {
     /// An existing reservation to set up and modify. If not provided, a new
     /// reservation will be created.
     reservation_id: Option<ReservationId>,
+
+    /// A specific serveme.tf server to reserve. If not provided, one will be
+    /// picked automatically.
+    #[command(autocomplete)]
+    server_id: Option<u32>,
+
+    /// Whether to enable server plugins (e.g. logs.tf uploads). Only for new
+    /// reservations. Defaults to true.
+    enable_plugins: Option<bool>,
+
+    /// Whether to upload the demo to demos.tf. Only for new reservations.
+    /// Defaults to true.
+    enable_demos_tf: Option<bool>,
 }
 
 impl HostCommand {
@@ -32,31 +48,47 @@ impl HostCommand {
     ) -> BotResult {
         interaction.defer_ephemeral(ctx).await?;
 
+        if self.reservation_id.is_some()
+            && (self.enable_plugins.is_some() || self.enable_demos_tf.is_some())
+        {
+            return Err(BotError::ReservationOptionsWithReservation);
+        }
+
         let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
         let rgl_match = RglMatch::get(self.match_id).await?;
 
         guild.ensure_time_open(&tx, rgl_match.match_date).await?;
 
+        guild.validate_schedule_time(rgl_match.match_date)?;
+
+        let overlap_warning = guild
+            .check_overlap(&tx, rgl_match.match_date, GameKind::Match, None)
+            .await?;
+
         let mut game = Game {
             guild_id: guild.id,
             timestamp: rgl_match.match_date,
-            server: self
-                .reservation_id
-                .map(GameServer::Hosted)
-                .unwrap_or_default(),
+            server: GameServer::Undecided,
             details: Match {
                 rgl_match_id: self.match_id,
+                vetoes: MapVetoes::default(),
             },
+            reservation_options: ReservationOptions::new(self.enable_plugins, self.enable_demos_tf),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
         };
 
-        let serveme_api_key = guild.serveme_api_key()?;
-
-        if game.server.is_hosted() {
-            game.edit_reservation(serveme_api_key).await?;
-        } else {
-            game.create_reservation(serveme_api_key).await?;
-        }
+        game.host(
+            &guild.serveme_account()?,
+            self.reservation_id,
+            self.server_id,
+        )
+        .await?;
 
         let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
 
@@ -66,14 +98,48 @@ impl HostCommand {
 
         tx.commit().await?;
 
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
         interaction
             .edit_response(
                 &ctx,
-                EditInteractionResponse::new()
-                    .embeds(vec![success_embed("Match scheduled."), embed]),
+                EditInteractionResponse::new().embeds(
+                    iter::once(success_embed("Match scheduled."))
+                        .chain(overlap_warning)
+                        .chain(iter::once(embed))
+                        .collect(),
+                ),
             )
             .await?;
 
         Ok(())
     }
 }
+
+impl HostCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::ServerId {
+                server_id,
+                match_id,
+                ..
+            } => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                let timestamp = match match_id.into_value() {
+                    Some(match_id) => Some(RglMatch::get(match_id).await?.match_date),
+                    None => None,
+                };
+
+                guild
+                    .autocomplete_servers(ctx, interaction, GameKind::Match, timestamp, &server_id)
+                    .await
+            }
+        }
+    }
+}
@@ -1,12 +1,13 @@
 use sea_orm::{ActiveModelTrait, IntoActiveModel};
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity::all::{CommandInteraction, Context, CreateActionRow, EditInteractionResponse};
 use serenity_commands::SubCommand;
 
 use crate::{
     Bot, BotResult,
+    components::ShowConnectInfoModalButton,
     entities::{
-        ConnectInfo,
-        game::{Game, GameServer, Match},
+        JoinInfo, MapVetoes,
+        game::{Game, GameServer, Match, ReservationOptions},
     },
     rgl::{RglMatch, RglMatchId},
     utils::success_embed,
@@ -17,8 +18,9 @@ pub struct JoinCommand {
     /// The ID of the RGL.gg match to join.
     match_id: RglMatchId,
 
-    /// The connect info for the other team's server.
-    connect_info: Option<ConnectInfo>,
+    /// The connect info or serveme.tf reservation link for the other team's
+    /// server.
+    connect_info: Option<JoinInfo>,
 }
 
 impl JoinCommand {
@@ -37,16 +39,27 @@ impl JoinCommand {
 
         guild.ensure_time_open(&tx, rgl_match.match_date).await?;
 
+        let server = if let Some(join_info) = self.connect_info {
+            GameServer::Joined(join_info.connect_info(&guild).await?)
+        } else {
+            GameServer::Undecided
+        };
+
         let game = Game {
             guild_id: guild.id,
             timestamp: rgl_match.match_date,
-            server: self
-                .connect_info
-                .map(GameServer::Joined)
-                .unwrap_or_default(),
+            server,
             details: Match {
                 rgl_match_id: self.match_id,
+                vetoes: MapVetoes::default(),
             },
+            reservation_options: ReservationOptions::default(),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
         };
 
         let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
@@ -57,13 +70,18 @@ impl JoinCommand {
 
         tx.commit().await?;
 
-        interaction
-            .edit_response(
-                &ctx,
-                EditInteractionResponse::new()
-                    .embeds(vec![success_embed("Match scheduled."), embed]),
-            )
-            .await?;
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
+        let mut edit =
+            EditInteractionResponse::new().embeds(vec![success_embed("Match scheduled."), embed]);
+
+        if !game.server.is_joined() {
+            edit = edit.components(vec![CreateActionRow::Buttons(vec![
+                ShowConnectInfoModalButton::create(&game),
+            ])]);
+        }
+
+        interaction.edit_response(&ctx, edit).await?;
 
         Ok(())
     }
@@ -1,16 +1,22 @@
+mod add_reservation;
 mod edit;
 mod host;
 mod join;
+mod veto;
 
 use serenity::all::{CommandInteraction, Context};
 use serenity_commands::Command;
 
-use self::{edit::EditCommand, host::HostCommand, join::JoinCommand};
+use self::{
+    add_reservation::AddReservationCommand, edit::EditCommand, host::HostCommand,
+    join::JoinCommand, veto::VetoCommand,
+};
 use crate::{Bot, BotResult};
 
 #[derive(Debug, Command)]
 pub enum MatchCommand {
     /// Add a hosted match to the schedule.
+    #[command(autocomplete)]
     Host(HostCommand),
 
     /// Add a joined match to the schedule.
@@ -19,9 +25,29 @@ pub enum MatchCommand {
     /// Edit an existing match.
     #[command(autocomplete)]
     Edit(EditCommand),
+
+    /// Record the map veto of a match.
+    #[command(autocomplete)]
+    Veto(VetoCommand),
+
+    /// Add a backup reservation to a hosted match, starting when its first
+    /// reservation ends.
+    #[command(autocomplete)]
+    AddReservation(AddReservationCommand),
 }
 
 impl MatchCommand {
+    /// Whether the command changes the schedule.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Host(_)
+            | Self::Join(_)
+            | Self::Edit(_)
+            | Self::Veto(_)
+            | Self::AddReservation(_) => true,
+        }
+    }
+
     pub async fn run(
         self,
         bot: &Bot,
@@ -32,6 +58,8 @@ impl MatchCommand {
             Self::Host(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Join(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Edit(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Veto(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::AddReservation(cmd) => cmd.run(bot, ctx, interaction).await,
         }
     }
 }
@@ -44,7 +72,10 @@ impl MatchCommandAutocomplete {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::Host(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Edit(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Veto(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::AddReservation(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
         }
     }
 }
@@ -0,0 +1,162 @@
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serenity::all::{
+    AutocompleteChoice, CommandInteraction, Context, CreateAutocompleteResponse,
+    CreateInteractionResponse, EditInteractionResponse,
+};
+use serenity_commands::{SubCommand, SubCommandGroup};
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::{
+        Map, MapVeto, VetoAction, VetoTeam,
+        game::{self, Game, Match},
+    },
+    rgl::{RglMatch, RglSeason},
+    utils::success_embed,
+};
+
+#[derive(Debug, SubCommandGroup)]
+pub enum VetoCommand {
+    /// Record the next ban or pick of a match's map veto.
+    #[command(autocomplete)]
+    Add(VetoAddCommand),
+
+    /// Clear the map veto of a match.
+    #[command(autocomplete)]
+    Clear(VetoClearCommand),
+}
+
+#[derive(Debug, SubCommand)]
+pub struct VetoAddCommand {
+    /// The match to record the veto for.
+    #[command(autocomplete)]
+    match_: OffsetDateTime,
+
+    /// The team making the ban or pick.
+    team: VetoTeam,
+
+    /// Whether the map was banned or picked.
+    action: VetoAction,
+
+    /// The map that was banned or picked.
+    #[command(autocomplete)]
+    map: Map,
+}
+
+#[derive(Debug, SubCommand)]
+pub struct VetoClearCommand {
+    /// The match to clear the veto of.
+    #[command(autocomplete)]
+    match_: OffsetDateTime,
+}
+
+impl VetoCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let datetime = match &self {
+            Self::Add(cmd) => cmd.match_,
+            Self::Clear(cmd) => cmd.match_,
+        };
+
+        let mut match_ = guild.get_game::<Match>(&tx, datetime).await?;
+
+        match self {
+            Self::Add(cmd) => match_.details.vetoes.0.push(MapVeto {
+                team: cmd.team,
+                action: cmd.action,
+                map: cmd.map,
+            }),
+            Self::Clear(_) => match_.details.vetoes.0.clear(),
+        }
+
+        let mut active_model = match_.into_active_model();
+        active_model.reset(game::Column::MapVetoes);
+
+        let game = active_model.update_versioned(&tx).await?;
+
+        let embed = Game::try_from(game)?.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embeds(vec![success_embed("Map veto updated."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl VetoCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Add(VetoAddCommandAutocomplete::Match { match_, .. })
+            | Self::Clear(VetoClearCommandAutocomplete::Match { match_ }) => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_games::<Match>(ctx, interaction, tx, None, &match_)
+                    .await
+            }
+            Self::Add(VetoAddCommandAutocomplete::Map { map, match_, .. }) => {
+                let choices = if let Some(datetime) = match_.into_value() {
+                    let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                    let match_ = guild.get_game::<Match>(&tx, datetime).await?;
+
+                    tx.commit().await?;
+
+                    let rgl_match = RglMatch::get(match_.details.rgl_match_id).await?;
+                    let season = RglSeason::get(rgl_match.season_id).await?;
+
+                    let pool = if season.maps.is_empty() {
+                        rgl_match.maps.iter().map(|m| m.map_name.clone()).collect()
+                    } else {
+                        season.maps.clone()
+                    };
+
+                    let query = map.trim().to_lowercase();
+
+                    pool.into_iter()
+                        .filter(|m| !match_.details.vetoes.0.iter().any(|v| v.map == *m))
+                        .filter(|m| m.to_lowercase().contains(&query))
+                        .take(25)
+                        .map(|m| AutocompleteChoice::new(m.to_string(), m.to_string()))
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+
+                interaction
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Autocomplete(
+                            CreateAutocompleteResponse::new().set_choices(choices),
+                        ),
+                    )
+                    .await?;
+
+                Ok(())
+            }
+        }
+    }
+}
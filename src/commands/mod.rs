@@ -1,26 +1,40 @@
+mod bot;
 mod config;
 mod game;
 mod r#match;
+mod ping;
+mod profile;
 mod refresh;
+mod rgl;
+mod schedule;
 mod scrim;
+mod season;
+mod team;
 
 use serenity::all::{
-    CommandInteraction, Context, InstallationContext, InteractionContext, Permissions,
-    ResolvedTarget,
+    CommandInteraction, Context, CreateAutocompleteResponse, CreateInteractionResponse,
+    InstallationContext, InteractionContext, Permissions, ResolvedTarget,
 };
 use serenity_commands::Commands;
 use tracing::instrument;
 
 use self::{
-    config::ConfigCommand, game::GameCommand, r#match::MatchCommand, refresh::RefreshCommand,
-    scrim::ScrimCommand,
+    bot::BotCommand, config::ConfigCommand, game::GameCommand, r#match::MatchCommand,
+    ping::PingCommand, profile::ProfileCommand, refresh::RefreshCommand, rgl::RglCommand,
+    schedule::ScheduleCommand, scrim::ScrimCommand, season::SeasonCommand, team::TeamCommand,
 };
-use crate::{Bot, BotResult, error::BotError, rgl::RglProfile};
+use crate::{Bot, BotResult, error::BotError, rgl::RglProfile, utils::warning_message};
+
+pub const MAINTENANCE_MESSAGE: &str = "The bot is under maintenance, so changes are temporarily \
+                                       disabled. Commands that only show information still work.";
 
 #[derive(Debug, Commands)]
 pub enum AllCommands {
     /// Configure the bot.
-    #[command(builder(default_member_permissions(Permissions::MANAGE_GUILD)))]
+    #[command(
+        autocomplete,
+        builder(default_member_permissions(Permissions::MANAGE_GUILD))
+    )]
     Config(ConfigCommand),
 
     /// Manage scrims.
@@ -44,10 +58,46 @@ pub enum AllCommands {
     )]
     Game(GameCommand),
 
+    /// Manage the schedule.
+    #[command(builder(default_member_permissions(Permissions::MANAGE_GUILD)))]
+    Schedule(ScheduleCommand),
+
+    /// Show RGL.gg season information.
+    Season(SeasonCommand),
+
+    /// Look up RGL.gg information.
+    Rgl(RglCommand),
+
+    /// Show information about the team.
+    Team(TeamCommand),
+
     /// Refresh the schedule.
     #[command(builder(default_member_permissions(Permissions::MANAGE_GUILD)))]
     Refresh(RefreshCommand),
 
+    /// Manage your linked Steam account.
+    #[command(builder(
+        add_integration_type(InstallationContext::User),
+        contexts(vec![
+            InteractionContext::Guild,
+            InteractionContext::PrivateChannel,
+        ])
+    ))]
+    Profile(ProfileCommand),
+
+    /// Show information about the bot.
+    Bot(BotCommand),
+
+    /// Check that the bot and its dependencies are responsive.
+    #[command(builder(
+        add_integration_type(InstallationContext::User),
+        contexts(vec![
+            InteractionContext::Guild,
+            InteractionContext::PrivateChannel,
+        ])
+    ))]
+    Ping(PingCommand),
+
     #[command(name = "RGL.gg Profile", context_menu = "user")]
     #[command(builder(
         add_integration_type(InstallationContext::User),
@@ -60,18 +110,60 @@ pub enum AllCommands {
 }
 
 impl AllCommands {
+    /// How many of the trailing commands are registered globally even when the
+    /// bot is restricted to specific guilds.
+    pub const GLOBAL_COMMANDS: usize = 2;
+
+    /// Whether the command writes any data, and so must be rejected while the
+    /// bot is in maintenance mode. Every new command has to be classified here.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Config(cmd) => cmd.is_mutating(),
+            Self::Scrim(cmd) => cmd.is_mutating(),
+            Self::Match(cmd) => cmd.is_mutating(),
+            Self::Game(cmd) => cmd.is_mutating(),
+            Self::Schedule(cmd) => cmd.is_mutating(),
+            Self::Profile(cmd) => cmd.is_mutating(),
+            Self::Refresh(_) => true,
+            Self::Season(_)
+            | Self::Rgl(_)
+            | Self::Team(_)
+            | Self::Bot(_)
+            | Self::Ping(_)
+            | Self::RglProfile => false,
+        }
+    }
+
     pub async fn run(
         self,
         bot: &Bot,
         ctx: &Context,
         interaction: &CommandInteraction,
     ) -> BotResult {
+        if bot.config.maintenance && self.is_mutating() {
+            interaction
+                .create_response(
+                    ctx,
+                    CreateInteractionResponse::Message(warning_message(MAINTENANCE_MESSAGE)),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
         match self {
             Self::Config(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Scrim(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Match(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Game(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Season(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Rgl(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Team(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Schedule(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Refresh(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Profile(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Bot(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Ping(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::RglProfile => {
                 let ResolvedTarget::User(user, _) = interaction
                     .data
@@ -84,7 +176,12 @@ impl AllCommands {
                 interaction.defer_ephemeral(ctx).await?;
 
                 interaction
-                    .edit_response(ctx, RglProfile::get_from_discord(user.id).await?.response())
+                    .edit_response(
+                        ctx,
+                        RglProfile::get_from_discord(&bot.db, user.id)
+                            .await?
+                            .response(),
+                    )
                     .await?;
 
                 Ok(())
@@ -101,10 +198,26 @@ impl AllCommandsAutocomplete {
         ctx: &Context,
         interaction: &CommandInteraction,
     ) -> BotResult {
-        match self {
+        let result = match self {
+            Self::Config(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Scrim(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Match(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Game(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+        };
+
+        match result {
+            // offer nothing rather than failing while serveme.tf calls are throttled.
+            Err(error) if error.is_serveme_rate_limited() => {
+                interaction
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Autocomplete(CreateAutocompleteResponse::new()),
+                    )
+                    .await?;
+
+                Ok(())
+            }
+            result => result,
         }
     }
 }
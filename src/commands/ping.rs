@@ -0,0 +1,84 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use sea_orm::EntityTrait;
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::Command;
+use tokio::time::timeout;
+
+use crate::{
+    Bot, BotResult,
+    entities::team_guild,
+    rgl,
+    serveme::{self, MapsRequest},
+    utils::embed,
+};
+
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, Command)]
+pub struct PingCommand;
+
+/// Run a health check, rendering whether it passed and how long it took.
+async fn check<T, E: Display>(future: impl Future<Output = Result<T, E>>) -> String {
+    let start = Instant::now();
+
+    match timeout(CHECK_TIMEOUT, future).await {
+        Ok(Ok(_)) => format!("✅ {}ms", start.elapsed().as_millis()),
+        Ok(Err(error)) => format!("❌ {error}"),
+        Err(_) => format!("❌ Timed out after {}s", CHECK_TIMEOUT.as_secs()),
+    }
+}
+
+impl PingCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let start = Instant::now();
+
+        interaction.defer_ephemeral(ctx).await?;
+
+        let latency = start.elapsed();
+
+        let database = check(bot.db.ping()).await;
+
+        // don't create a guild row just to look for an API key.
+        let guild = match interaction.guild_id {
+            Some(guild_id) => team_guild::Entity::find_by_id(guild_id)
+                .one(&bot.db)
+                .await
+                .ok()
+                .flatten(),
+            None => None,
+        };
+
+        let serveme_host = guild.as_ref().map_or_else(
+            || serveme::default_host().to_owned(),
+            |guild| guild.serveme_host().to_owned(),
+        );
+
+        let serveme = match guild.and_then(|guild| guild.try_serveme_account()) {
+            Some(account) => check(MapsRequest::send(&account, None)).await,
+            None => "➖ No API key configured".to_owned(),
+        };
+
+        let rgl = check(rgl::ping()).await;
+
+        let embed = embed("🏓 Pong!")
+            .field("Discord", format!("✅ {}ms", latency.as_millis()), true)
+            .field("Database", database, true)
+            .field(serveme_host, serveme, true)
+            .field("RGL.gg", rgl, true);
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use sea_orm::{ActiveValue::Set, EntityTrait, sea_query::OnConflict};
+use serenity::all::{CommandInteraction, Context, CreateInteractionResponse};
+use serenity_commands::Command;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::{LinkedUserId, steam_mapping},
+    error::BotError,
+    rgl::{RglProfile, SteamId},
+    utils::{create_message, success_embed},
+};
+
+#[derive(Debug, Command)]
+pub enum ProfileCommand {
+    /// Link your Steam account to your Discord account.
+    Link {
+        /// Your 64-bit Steam ID or Steam community profile URL.
+        steam_id: SteamId,
+    },
+
+    /// Unlink your Steam account from your Discord account.
+    Unlink,
+}
+
+impl ProfileCommand {
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Link { .. } | Self::Unlink => true,
+        }
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let user_id = LinkedUserId::from(interaction.user.id);
+
+        let message = match self {
+            Self::Link { steam_id } => {
+                steam_mapping::Entity::insert(steam_mapping::ActiveModel {
+                    user_id: Set(user_id),
+                    steam_id: Set(steam_id.into()),
+                    linked_at: Set(OffsetDateTime::now_utc()),
+                })
+                .on_conflict(
+                    OnConflict::column(steam_mapping::Column::UserId)
+                        .update_columns([
+                            steam_mapping::Column::SteamId,
+                            steam_mapping::Column::LinkedAt,
+                        ])
+                        .to_owned(),
+                )
+                .exec(&bot.db)
+                .await?;
+
+                format!("Linked Steam account `{steam_id}`.")
+            }
+            Self::Unlink => {
+                let res = steam_mapping::Entity::delete_by_id(user_id)
+                    .exec(&bot.db)
+                    .await?;
+
+                if res.rows_affected == 0 {
+                    return Err(BotError::NoSteamMapping);
+                }
+
+                "Unlinked Steam account.".to_owned()
+            }
+        };
+
+        RglProfile::invalidate_discord(interaction.user.id).await;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(create_message().embed(success_embed(message))),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use serenity::all::{
+    CommandInteraction, Context, EditInteractionResponse, FormattedTimestamp,
+    FormattedTimestampStyle,
+};
+use serenity_commands::Command;
+
+use crate::{
+    Bot, BotResult,
+    error::BotError,
+    rgl::{RglMatch, RglMatchId, RglSeason},
+    utils::{embed, warning_embed},
+};
+
+#[derive(Debug, Command)]
+pub enum RglCommand {
+    /// Show the details of an RGL.gg match.
+    Match {
+        /// The ID of the RGL.gg match.
+        match_id: RglMatchId,
+    },
+}
+
+impl RglCommand {
+    pub async fn run(self, _: &Bot, ctx: &Context, interaction: &CommandInteraction) -> BotResult {
+        match self {
+            Self::Match { match_id } => {
+                interaction.defer_ephemeral(ctx).await?;
+
+                let rgl_match = match RglMatch::get(match_id).await {
+                    Ok(rgl_match) => rgl_match,
+                    Err(BotError::Arc(error)) if matches!(*error, BotError::RglUnavailable) => {
+                        interaction
+                            .edit_response(
+                                &ctx,
+                                EditInteractionResponse::new().embed(warning_embed(format!(
+                                    "{}\nView the match on [RGL.gg]({}).",
+                                    BotError::RglUnavailable,
+                                    match_id.url()
+                                ))),
+                            )
+                            .await?;
+
+                        return Ok(());
+                    }
+                    Err(error) => return Err(error),
+                };
+
+                let (home, away) = &rgl_match.teams;
+
+                let mut embed = embed(format!("🏆 {}", rgl_match.match_name))
+                    .url(match_id.url())
+                    .field(
+                        "Teams",
+                        format!(
+                            "[{}]({}) vs. [{}]({})",
+                            home.team_name,
+                            home.team_id.url(),
+                            away.team_name,
+                            away.team_id.url()
+                        ),
+                        false,
+                    )
+                    .field(
+                        "Date",
+                        FormattedTimestamp::new(
+                            rgl_match.match_date.into(),
+                            Some(FormattedTimestampStyle::LongDateTime),
+                        )
+                        .to_string(),
+                        true,
+                    );
+
+                if let Ok(season) = RglSeason::get(rgl_match.season_id).await {
+                    embed = embed
+                        .field(
+                            "Season",
+                            format!("[{}]({})", season.season_name, rgl_match.season_id.url()),
+                            true,
+                        )
+                        .field("Format", season.format_name.to_string(), true);
+                }
+
+                let maps = if rgl_match.maps.is_empty() {
+                    "Not announced".to_owned()
+                } else {
+                    rgl_match
+                        .maps
+                        .iter()
+                        .map(|m| format!("`{}`", m.map_name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+
+                embed = embed.field("Maps", maps, false);
+
+                if let Some((home_score, away_score)) = home.score.zip(away.score) {
+                    embed = embed.field("Score", format!("{home_score} - {away_score}"), true);
+                }
+
+                interaction
+                    .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::Command;
+use time::Duration;
+
+use crate::{Bot, BotResult, error::BotError, utils::success_embed};
+
+#[derive(Debug, Command)]
+pub enum ScheduleCommand {
+    /// Delete games older than a number of days.
+    Prune {
+        /// Games that started more than this many days ago will be deleted.
+        days: u16,
+    },
+}
+
+impl ScheduleCommand {
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Prune { .. } => true,
+        }
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Prune { days } => {
+                if days == 0 {
+                    return Err(BotError::InvalidPruneDays);
+                }
+
+                interaction.defer_ephemeral(ctx).await?;
+
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                let deleted = guild
+                    .cleanup_old_games(&tx, Duration::days(days.into()))
+                    .await?;
+
+                tx.commit().await?;
+
+                interaction
+                    .edit_response(
+                        &ctx,
+                        EditInteractionResponse::new().embed(success_embed(format!(
+                            "Deleted {deleted} game{} older than {days} day{}.",
+                            if deleted == 1 { "" } else { "s" },
+                            if days == 1 { "" } else { "s" },
+                        ))),
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
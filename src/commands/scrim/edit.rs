@@ -1,13 +1,17 @@
 use paste::paste;
 use sea_orm::{ActiveModelTrait, EntityTrait, IntoActiveModel, QuerySelect};
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse, UserId};
+use serenity::all::{
+    CommandInteraction, Context, CreateMessage, EditInteractionResponse, FormattedTimestamp,
+    FormattedTimestampStyle, UserId,
+};
 use serenity_commands::{SubCommand, SubCommandGroup};
 use time::OffsetDateTime;
+use tracing::warn;
 
 use crate::{
     Bot, BotResult,
     entities::{
-        ConnectInfo, GameFormat, MapList, ReservationId,
+        ConnectInfo, GameFormat, MapList, OpponentUserId, ReservationId,
         game::{self, Game, GameServer, Scrim},
         team_guild,
     },
@@ -71,11 +75,11 @@ macro_rules! edit_command {
                     let game = match self {
                         $(
                             Self::$name(cmd) => {
-                                cmd.run(&guild, scrim).await?
+                                cmd.run(ctx, &guild, scrim).await?
                             }
                         )*
                     }
-                    .update(&tx)
+                    .update_versioned(&tx)
                     .await?;
 
                     let embed = Game::try_from(game)?.embed(&guild).await?;
@@ -114,6 +118,22 @@ edit_command! {
         opponent: Option<UserId>,
     },
 
+    "opposing team's contact, picked from previous opponents"
+    RecentOpponent {
+        #[command(autocomplete)]
+        recent_opponent: Option<OpponentUserId>,
+    },
+
+    "opposing team's name"
+    OpponentName {
+        opponent_name: Option<String>,
+    },
+
+    "opposing team's contact link (e.g. a Discord tag or Steam profile)"
+    OpponentContact {
+        opponent_contact: Option<String>,
+    },
+
     "game format of the scrim"
     GameFormat {
         game_format: GameFormat,
@@ -138,20 +158,22 @@ edit_command! {
 }
 
 impl EditDateTimeCommand {
-    #[allow(clippy::unused_async)]
     pub async fn run(
         self,
+        ctx: &Context,
         guild: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
         scrim.timestamp = self.date_time;
 
         if scrim.server.is_hosted() {
-            let api_key = guild.serveme_api_key()?;
+            let account = &guild.serveme_account()?;
 
-            scrim.edit_reservation(api_key).await?;
+            scrim.edit_reservation(account).await?;
         }
 
+        scrim.sync_discord_event(ctx).await;
+
         let mut active_model = scrim.into_active_model();
         active_model.reset(game::Column::Timestamp);
 
@@ -160,16 +182,118 @@ impl EditDateTimeCommand {
 }
 
 impl EditOpponentCommand {
+    pub async fn run(
+        self,
+        ctx: &Context,
+        guild: &team_guild::Model,
+        scrim: Game<Scrim>,
+    ) -> BotResult<game::ActiveModel> {
+        set_opponent(ctx, guild, scrim, self.opponent.map(Into::into)).await
+    }
+}
+
+impl EditRecentOpponentCommand {
+    pub async fn run(
+        self,
+        ctx: &Context,
+        guild: &team_guild::Model,
+        scrim: Game<Scrim>,
+    ) -> BotResult<game::ActiveModel> {
+        set_opponent(ctx, guild, scrim, self.recent_opponent).await
+    }
+}
+
+async fn set_opponent(
+    ctx: &Context,
+    guild: &team_guild::Model,
+    mut scrim: Game<Scrim>,
+    opponent: Option<OpponentUserId>,
+) -> BotResult<game::ActiveModel> {
+    let was_open = scrim.details.opponent_display().is_none();
+
+    scrim.details.opponent_user_id = opponent;
+
+    if was_open && opponent.is_some() {
+        notify_opponent_found(ctx, guild, &scrim).await;
+    }
+
+    let mut active_model = scrim.into_active_model();
+    active_model.reset(game::Column::OpponentUserId);
+
+    Ok(active_model)
+}
+
+/// Announce that an open scrim slot has been filled. Failures are logged
+/// rather than returned, so that they don't block the edit itself.
+async fn notify_opponent_found(ctx: &Context, guild: &team_guild::Model, scrim: &Game<Scrim>) {
+    let Some(channel) = guild
+        .reminder_channel_id
+        .map(|c| c.0)
+        .or_else(|| guild.schedule_channel_id.map(|c| c.0))
+    else {
+        return;
+    };
+
+    let entry = match scrim
+        .clone()
+        .into_scrim_or_match()
+        .schedule_entry(guild, false, None)
+        .await
+    {
+        Ok(entry) => entry,
+        Err(error) => {
+            warn!(?guild.id, ?error, "failed to build scrim confirmation");
+            return;
+        }
+    };
+
+    let opponent = scrim.details.opponent_display().unwrap_or_default();
+    let time = FormattedTimestamp::new(
+        scrim.timestamp.into(),
+        Some(FormattedTimestampStyle::LongDateTime),
+    );
+
+    let embed = success_embed(format!(
+        "Scrim vs. {opponent} confirmed for {time}.\n\n{entry}"
+    ));
+
+    if let Err(error) = channel
+        .send_message(ctx, CreateMessage::new().embed(embed))
+        .await
+    {
+        warn!(?guild.id, ?error, "failed to send scrim confirmation");
+    }
+}
+
+impl EditOpponentNameCommand {
+    #[allow(clippy::unused_async)]
+    pub async fn run(
+        self,
+        _: &Context,
+        _: &team_guild::Model,
+        mut scrim: Game<Scrim>,
+    ) -> BotResult<game::ActiveModel> {
+        scrim.details.opponent_name = self.opponent_name;
+
+        let mut active_model = scrim.into_active_model();
+        active_model.reset(game::Column::OpponentName);
+
+        Ok(active_model)
+    }
+}
+
+impl EditOpponentContactCommand {
     #[allow(clippy::unused_async)]
     pub async fn run(
         self,
+        _: &Context,
         _: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
-        scrim.details.opponent_user_id = self.opponent.map(Into::into);
+        scrim.details.opponent_contact = self.opponent_contact;
 
         let mut active_model = scrim.into_active_model();
-        active_model.reset(game::Column::OpponentUserId);
+        active_model.reset(game::Column::OpponentContact);
 
         Ok(active_model)
     }
@@ -179,15 +303,16 @@ impl EditGameFormatCommand {
     #[allow(clippy::unused_async)]
     pub async fn run(
         self,
+        _: &Context,
         guild: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
         scrim.details.game_format = self.game_format;
 
         if scrim.server.is_hosted() {
-            let api_key = guild.serveme_api_key()?;
+            let account = &guild.serveme_account()?;
 
-            scrim.edit_reservation(api_key).await?;
+            scrim.edit_reservation(account).await?;
         }
 
         let mut active_model = scrim.into_active_model();
@@ -200,15 +325,19 @@ impl EditGameFormatCommand {
 impl EditMapsCommand {
     pub async fn run(
         self,
+        _: &Context,
         guild: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
-        scrim.details.maps = self.maps.unwrap_or_default();
+        let maps = self.maps.unwrap_or_default();
+        maps.validate()?;
+
+        scrim.details.maps = maps;
 
         if scrim.server.is_hosted() {
-            let api_key = guild.serveme_api_key()?;
+            let account = &guild.serveme_account()?;
 
-            scrim.edit_reservation(api_key).await?;
+            scrim.edit_reservation(account).await?;
         }
 
         let mut active_model = scrim.into_active_model();
@@ -221,6 +350,7 @@ impl EditMapsCommand {
 impl EditReservationIdCommand {
     pub async fn run(
         self,
+        _: &Context,
         guild: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
@@ -231,14 +361,15 @@ impl EditReservationIdCommand {
         }
 
         if scrim.server.is_hosted() {
-            let api_key = guild.serveme_api_key()?;
+            let account = &guild.serveme_account()?;
 
-            scrim.edit_reservation(api_key).await?;
+            scrim.edit_reservation(account).await?;
         }
 
+        scrim.credentials = None;
+
         let mut active_model = scrim.into_active_model();
-        active_model.reset(game::Column::ReservationId);
-        active_model.reset(game::Column::ConnectInfo);
+        active_model.reset_server();
 
         Ok(active_model)
     }
@@ -248,6 +379,7 @@ impl EditConnectInfoCommand {
     #[allow(clippy::unused_async)]
     pub async fn run(
         self,
+        _: &Context,
         _: &team_guild::Model,
         mut scrim: Game<Scrim>,
     ) -> BotResult<game::ActiveModel> {
@@ -257,9 +389,10 @@ impl EditConnectInfoCommand {
             scrim.server = GameServer::Undecided;
         }
 
+        scrim.credentials = None;
+
         let mut active_model = scrim.into_active_model();
-        active_model.reset(game::Column::ReservationId);
-        active_model.reset(game::Column::ConnectInfo);
+        active_model.reset_server();
 
         Ok(active_model)
     }
@@ -275,6 +408,9 @@ impl EditCommandAutocomplete {
         match self {
             Self::DateTime(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Opponent(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::RecentOpponent(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::OpponentName(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::OpponentContact(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::GameFormat(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Maps(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::ReservationId(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
@@ -313,7 +449,39 @@ macro_rules! impl_autocomplete_scrim {
     };
 }
 
-impl_autocomplete_scrim!(Opponent, GameFormat, ConnectInfo);
+impl_autocomplete_scrim!(
+    Opponent,
+    OpponentName,
+    OpponentContact,
+    GameFormat,
+    ConnectInfo
+);
+
+impl EditRecentOpponentCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        match self {
+            Self::Scrim { scrim, .. } => {
+                guild
+                    .autocomplete_games::<Scrim>(ctx, interaction, tx, None, &scrim)
+                    .await
+            }
+            Self::RecentOpponent {
+                recent_opponent, ..
+            } => {
+                guild
+                    .autocomplete_opponents(ctx, interaction, tx, &recent_opponent)
+                    .await
+            }
+        }
+    }
+}
 
 impl EditDateTimeCommandAutocomplete {
     pub async fn autocomplete(
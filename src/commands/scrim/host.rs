@@ -1,16 +1,22 @@
+use std::iter;
+
 use sea_orm::{ActiveModelTrait, IntoActiveModel};
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse, UserId};
+use serenity::all::{
+    CommandInteraction, Context, CreateEmbed, EditInteractionResponse, FormattedTimestamp,
+    FormattedTimestampStyle, UserId,
+};
 use serenity_commands::SubCommand;
 use time::OffsetDateTime;
 
 use crate::{
     Bot, BotResult,
     entities::{
-        GameFormat, MapList, ReservationId,
-        game::{Game, GameServer, Scrim},
+        GameFormat, MapList, OpponentUserId, ReservationId, ServerConfig,
+        game::{Game, GameKind, GameServer, ReservationOptions, Scrim},
     },
     error::BotError,
-    utils::success_embed,
+    serveme::{CreateReservationRequest, Server},
+    utils::{embed, success_embed},
 };
 
 #[derive(Clone, Debug, SubCommand)]
@@ -23,18 +29,44 @@ pub struct HostCommand {
     /// not in the server.
     opponent: Option<UserId>,
 
-    /// Space-separated list of maps to be played.
+    /// A previous opponent's contacted team member, instead of `opponent`.
+    #[command(autocomplete)]
+    recent_opponent: Option<OpponentUserId>,
+
+    /// Space-separated list of maps to be played. Defaults to the template's
+    /// maps or the guild's default maps.
     #[command(autocomplete)]
     maps: Option<MapList>,
 
-    /// The game format of the scrim. Defaults to the guild's default game
-    /// format.
+    /// The game format of the scrim. Defaults to the template's or guild's
+    /// default game format.
     game_format: Option<GameFormat>,
 
+    /// A saved template to take the game format and maps from.
+    #[command(autocomplete)]
+    template: Option<String>,
+
     /// An existing reservation to set up and modify. If not provided, a new
     /// reservation will be created.
     #[command(autocomplete)]
     reservation_id: Option<ReservationId>,
+
+    /// A specific serveme.tf server to reserve. If not provided, one will be
+    /// picked automatically.
+    #[command(autocomplete)]
+    server_id: Option<u32>,
+
+    /// Whether to enable server plugins (e.g. logs.tf uploads). Only for new
+    /// reservations. Defaults to true.
+    enable_plugins: Option<bool>,
+
+    /// Whether to upload the demo to demos.tf. Only for new reservations.
+    /// Defaults to true.
+    enable_demos_tf: Option<bool>,
+
+    /// Preview the server, config and times that would be reserved, without
+    /// reserving anything or scheduling the scrim. Defaults to false.
+    dry_run: Option<bool>,
 }
 
 impl HostCommand {
@@ -47,35 +79,97 @@ impl HostCommand {
     ) -> BotResult {
         interaction.defer_ephemeral(ctx).await?;
 
+        let opponent = OpponentUserId::pick(self.opponent, self.recent_opponent)?;
+
+        if self.reservation_id.is_some()
+            && (self.enable_plugins.is_some() || self.enable_demos_tf.is_some())
+        {
+            return Err(BotError::ReservationOptionsWithReservation);
+        }
+
         let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
         guild.ensure_time_open(&tx, self.date_time).await?;
 
+        guild.validate_schedule_time(self.date_time)?;
+
+        let overlap_warning = guild
+            .check_overlap(&tx, self.date_time, GameKind::Scrim, None)
+            .await?;
+
+        let template = match &self.template {
+            Some(name) => Some(guild.get_scrim_template(&tx, name).await?),
+            None => None,
+        };
+
+        let (template_format, template_maps) = template.map(|t| (t.game_format, t.maps)).unzip();
+
         let mut game = Game {
             guild_id: guild.id,
             timestamp: self.date_time,
-            server: self
-                .reservation_id
-                .map(GameServer::Hosted)
-                .unwrap_or_default(),
+            server: GameServer::Undecided,
             details: Scrim {
-                opponent_user_id: self.opponent.map(Into::into),
+                opponent_user_id: opponent,
+                opponent_name: None,
+                opponent_contact: None,
                 game_format: self
                     .game_format
+                    .or(template_format)
                     .or(guild.game_format)
                     .ok_or(BotError::NoGameFormat)?,
-                maps: self.maps.unwrap_or_default(),
+                maps: self
+                    .maps
+                    .or(template_maps)
+                    .or_else(|| guild.default_maps.clone())
+                    .unwrap_or_default(),
+                result: None,
             },
+            reservation_options: ReservationOptions::new(self.enable_plugins, self.enable_demos_tf),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
         };
 
-        let serveme_api_key = guild.serveme_api_key()?;
+        if self.dry_run.unwrap_or(false) {
+            if self.reservation_id.is_some() {
+                return Err(BotError::DryRunWithReservation);
+            }
+
+            let (starts_at, ends_at) = game.start_end_times();
+
+            let (request, server) = game
+                .reservation_request(
+                    &guild.serveme_account()?,
+                    self.server_id,
+                    starts_at,
+                    ends_at,
+                )
+                .await?;
 
-        if game.server.is_hosted() {
-            game.edit_reservation(serveme_api_key).await?;
-        } else {
-            game.create_reservation(serveme_api_key).await?;
+            interaction
+                .edit_response(
+                    &ctx,
+                    EditInteractionResponse::new().embeds(
+                        iter::once(dry_run_embed(&request, &server))
+                            .chain(overlap_warning)
+                            .collect(),
+                    ),
+                )
+                .await?;
+
+            return Ok(());
         }
 
+        game.host(
+            &guild.serveme_account()?,
+            self.reservation_id,
+            self.server_id,
+        )
+        .await?;
+
         let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
 
         let embed = game.embed(&guild).await?;
@@ -84,11 +178,17 @@ impl HostCommand {
 
         tx.commit().await?;
 
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
         interaction
             .edit_response(
                 &ctx,
-                EditInteractionResponse::new()
-                    .embeds(vec![success_embed("Scrim scheduled."), embed]),
+                EditInteractionResponse::new().embeds(
+                    iter::once(success_embed("Scrim scheduled."))
+                        .chain(overlap_warning)
+                        .chain(iter::once(embed))
+                        .collect(),
+                ),
             )
             .await?;
 
@@ -96,6 +196,38 @@ impl HostCommand {
     }
 }
 
+fn dry_run_embed(request: &CreateReservationRequest, server: &Server) -> CreateEmbed {
+    let config = request
+        .server_config_id
+        .and_then(|id| ServerConfig::ALL.iter().find(|config| config.id == id))
+        .map_or("None", |config| config.name);
+
+    let first_map = request
+        .first_map
+        .as_ref()
+        .map_or_else(|| "None".to_owned(), |map| format!("`{map}`"));
+
+    let timestamp = |datetime: OffsetDateTime| {
+        FormattedTimestamp::new(
+            datetime.into(),
+            Some(FormattedTimestampStyle::ShortDateTime),
+        )
+        .to_string()
+    };
+
+    embed("🧪 Dry Run")
+        .description("Nothing was reserved or scheduled.")
+        .field(
+            "Server",
+            format!("{} (`{}`)", server.name, server.ip_and_port),
+            false,
+        )
+        .field("Config", config, true)
+        .field("First Map", first_map, true)
+        .field("Starts", timestamp(request.starts_at), true)
+        .field("Ends", timestamp(request.ends_at), true)
+}
+
 impl HostCommandAutocomplete {
     pub async fn autocomplete(
         self,
@@ -104,6 +236,15 @@ impl HostCommandAutocomplete {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::RecentOpponent {
+                recent_opponent, ..
+            } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_opponents(ctx, interaction, tx, &recent_opponent)
+                    .await
+            }
             Self::DateTime { date_time, .. } => {
                 let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
@@ -120,6 +261,13 @@ impl HostCommandAutocomplete {
                     .autocomplete_maps(ctx, interaction, game_format.flatten().into_value(), &maps)
                     .await
             }
+            Self::Template { template, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_scrim_templates(ctx, interaction, tx, &template)
+                    .await
+            }
             Self::ReservationId { reservation_id, .. } => {
                 let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
@@ -133,6 +281,23 @@ impl HostCommandAutocomplete {
                     )
                     .await
             }
+            Self::ServerId {
+                server_id,
+                date_time,
+                ..
+            } => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_servers(
+                        ctx,
+                        interaction,
+                        GameKind::Scrim,
+                        date_time.into_value(),
+                        &server_id,
+                    )
+                    .await
+            }
         }
     }
 }
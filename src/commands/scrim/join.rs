@@ -1,15 +1,19 @@
 use sea_orm::{ActiveModelTrait, IntoActiveModel};
-use serenity::all::{CommandInteraction, Context, EditInteractionResponse, UserId};
+use serenity::all::{
+    CommandInteraction, Context, CreateActionRow, EditInteractionResponse, UserId,
+};
 use serenity_commands::SubCommand;
 use time::OffsetDateTime;
 
 use crate::{
     Bot, BotResult,
+    components::ShowConnectInfoModalButton,
     entities::{
-        ConnectInfo, GameFormat, MapList,
-        game::{Game, GameServer, Scrim},
+        GameFormat, JoinInfo, MapList, OpponentUserId,
+        game::{Game, GameServer, ReservationOptions, Scrim},
     },
     error::BotError,
+    serveme::GetReservationRequest,
     utils::success_embed,
 };
 
@@ -23,6 +27,10 @@ pub struct JoinCommand {
     /// not in the server.
     opponent: Option<UserId>,
 
+    /// A previous opponent's contacted team member, instead of `opponent`.
+    #[command(autocomplete)]
+    recent_opponent: Option<OpponentUserId>,
+
     /// Space-separated list of maps to be played.
     #[command(autocomplete)]
     maps: Option<MapList>,
@@ -31,8 +39,9 @@ pub struct JoinCommand {
     /// format.
     game_format: Option<GameFormat>,
 
-    /// The connect info for the other team's server.
-    connect_info: Option<ConnectInfo>,
+    /// The connect info or serveme.tf reservation link for the other team's
+    /// server.
+    connect_info: Option<JoinInfo>,
 }
 
 impl JoinCommand {
@@ -45,25 +54,54 @@ impl JoinCommand {
     ) -> BotResult {
         interaction.defer_ephemeral(ctx).await?;
 
+        let opponent = OpponentUserId::pick(self.opponent, self.recent_opponent)?;
+
         let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
         guild.ensure_time_open(&tx, self.date_time).await?;
 
+        let maps = match (self.maps, &self.connect_info) {
+            (Some(maps), _) => maps,
+            // a shared reservation link already says which map is played first.
+            (None, Some(JoinInfo::Reservation(reservation_id))) => {
+                GetReservationRequest::send(&guild.serveme_account()?, *reservation_id)
+                    .await?
+                    .first_map
+                    .clone()
+                    .map(|map| MapList(vec![map]))
+                    .unwrap_or_default()
+            }
+            (None, _) => MapList::default(),
+        };
+
+        let server = if let Some(join_info) = self.connect_info {
+            GameServer::Joined(join_info.connect_info(&guild).await?)
+        } else {
+            GameServer::Undecided
+        };
+
         let game = Game {
             guild_id: guild.id,
             timestamp: self.date_time,
-            server: self
-                .connect_info
-                .map(GameServer::Joined)
-                .unwrap_or_default(),
+            server,
             details: Scrim {
-                opponent_user_id: self.opponent.map(Into::into),
+                opponent_user_id: opponent,
+                opponent_name: None,
+                opponent_contact: None,
                 game_format: self
                     .game_format
                     .or(guild.game_format)
                     .ok_or(BotError::NoGameFormat)?,
-                maps: self.maps.unwrap_or_default(),
+                maps,
+                result: None,
             },
+            reservation_options: ReservationOptions::default(),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
         };
 
         let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
@@ -74,13 +112,18 @@ impl JoinCommand {
 
         tx.commit().await?;
 
-        interaction
-            .edit_response(
-                &ctx,
-                EditInteractionResponse::new()
-                    .embeds(vec![success_embed("Scrim scheduled."), embed]),
-            )
-            .await?;
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
+        let mut edit =
+            EditInteractionResponse::new().embeds(vec![success_embed("Scrim scheduled."), embed]);
+
+        if !game.server.is_joined() {
+            edit = edit.components(vec![CreateActionRow::Buttons(vec![
+                ShowConnectInfoModalButton::create(&game),
+            ])]);
+        }
+
+        interaction.edit_response(&ctx, edit).await?;
 
         Ok(())
     }
@@ -94,6 +137,15 @@ impl JoinCommandAutocomplete {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::RecentOpponent {
+                recent_opponent, ..
+            } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_opponents(ctx, interaction, tx, &recent_opponent)
+                    .await
+            }
             Self::DateTime { date_time, .. } => {
                 let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
 
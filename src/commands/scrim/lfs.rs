@@ -5,7 +5,7 @@ use serenity::all::{
     CommandInteraction, Context, CreateEmbed, EditInteractionResponse, Mentionable,
 };
 use serenity_commands::SubCommand;
-use time::{Date, Time};
+use time::{Date, OffsetDateTime, Time};
 
 use crate::{
     Bot, BotResult,
@@ -14,7 +14,7 @@ use crate::{
         game::{self, ScrimOrMatch},
     },
     error::BotError,
-    utils::{OffsetDateTimeEtExt, lfs_date_string, lfs_date_string_single, lfs_time_string},
+    utils::{lfs_date_string, lfs_date_string_single, lfs_time_string, tz_offset},
 };
 
 #[derive(Clone, Debug, SubCommand)]
@@ -52,20 +52,28 @@ impl LfsCommand {
             .ok_or(BotError::NoDivision)?;
 
         let games = guild
-            .select_games::<ScrimOrMatch>(|s| {
+            .select_games::<ScrimOrMatch>(None, |s| {
                 s.filter(game::Column::OpponentUserId.is_null())
                     .filter(game::Column::GameFormat.eq(game_format))
             })
             .all(&tx)
             .await?;
 
+        // LFS channels are shared by every team, so times there are always ET.
+        let to_et = |datetime: OffsetDateTime| {
+            datetime.to_offset(tz_offset(tzdb::time_zone::america::NEW_YORK, datetime))
+        };
+
+        let today = to_et(OffsetDateTime::now_utc()).date();
+
         let mut map = BTreeMap::<Date, Vec<Time>>::new();
 
         for game in games {
-            let date = game.timestamp.date_et();
-            let time = game.timestamp.time_et();
+            let timestamp = to_et(game.timestamp);
 
-            map.entry(date).or_default().push(time);
+            map.entry(timestamp.date())
+                .or_default()
+                .push(timestamp.time());
         }
 
         let timings = match map.len() {
@@ -76,7 +84,7 @@ impl LfsCommand {
                 let (date, times) = map.into_iter().next().unwrap();
                 format!(
                     " {}{}",
-                    lfs_date_string_single(date),
+                    lfs_date_string_single(date, today),
                     times
                         .into_iter()
                         .map(lfs_time_string)
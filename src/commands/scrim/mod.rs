@@ -2,19 +2,52 @@ mod edit;
 mod host;
 mod join;
 mod lfs;
+mod quickhost;
+mod rehost;
+mod result;
+mod series;
+mod show;
+mod stats;
+mod template;
+mod unhost;
 
 use serenity::all::{CommandInteraction, Context};
 use serenity_commands::Command;
 
-use self::{edit::EditCommand, host::HostCommand, join::JoinCommand, lfs::LfsCommand};
+use self::{
+    edit::EditCommand, host::HostCommand, join::JoinCommand, lfs::LfsCommand,
+    quickhost::QuickhostCommand, rehost::RehostCommand, result::ResultCommand,
+    series::SeriesCommand, show::ShowCommand, stats::StatsCommand, template::TemplateCommand,
+    unhost::UnhostCommand,
+};
 use crate::{Bot, BotResult};
 
 #[derive(Debug, Command)]
 pub enum ScrimCommand {
+    /// Show the details of a scrim, including open LFS slots.
+    #[command(autocomplete)]
+    Show(ShowCommand),
+
     /// Host a new scrim.
     #[command(autocomplete)]
     Host(HostCommand),
 
+    /// Host an open scrim slot using the guild's default format and maps.
+    #[command(autocomplete)]
+    Quickhost(QuickhostCommand),
+
+    /// Host a best-of-N series of scrims against the same opponent.
+    #[command(autocomplete)]
+    Series(SeriesCommand),
+
+    /// Give up a scrim's server, keeping the scrim on the schedule.
+    #[command(autocomplete)]
+    Unhost(UnhostCommand),
+
+    /// Host a server for a scrim that doesn't have one.
+    #[command(autocomplete)]
+    Rehost(RehostCommand),
+
     /// Join a scrim hosted by another team.
     #[command(autocomplete)]
     Join(JoinCommand),
@@ -25,9 +58,36 @@ pub enum ScrimCommand {
 
     /// Generate Looking for Scrim messages.
     Lfs(LfsCommand),
+
+    /// Record the result of a scrim.
+    #[command(autocomplete)]
+    Result(ResultCommand),
+
+    /// Show the team's scrim record.
+    Stats(StatsCommand),
+
+    /// Manage saved scrim templates.
+    #[command(autocomplete)]
+    Template(TemplateCommand),
 }
 
 impl ScrimCommand {
+    /// Whether the command changes the schedule or the guild's templates.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Host(_)
+            | Self::Quickhost(_)
+            | Self::Series(_)
+            | Self::Unhost(_)
+            | Self::Rehost(_)
+            | Self::Join(_)
+            | Self::Edit(_)
+            | Self::Result(_) => true,
+            Self::Template(cmd) => cmd.is_mutating(),
+            Self::Show(_) | Self::Lfs(_) | Self::Stats(_) => false,
+        }
+    }
+
     pub async fn run(
         self,
         bot: &Bot,
@@ -35,10 +95,18 @@ impl ScrimCommand {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::Show(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Host(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Quickhost(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Series(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Unhost(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Rehost(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Join(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Edit(cmd) => cmd.run(bot, ctx, interaction).await,
             Self::Lfs(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Result(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Stats(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::Template(cmd) => cmd.run(bot, ctx, interaction).await,
         }
     }
 }
@@ -51,9 +119,16 @@ impl ScrimCommandAutocomplete {
         interaction: &CommandInteraction,
     ) -> BotResult {
         match self {
+            Self::Show(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Host(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Quickhost(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Series(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Unhost(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Rehost(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Join(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
             Self::Edit(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Result(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
+            Self::Template(cmd) => cmd.autocomplete(bot, ctx, interaction).await,
         }
     }
 }
@@ -0,0 +1,106 @@
+use std::iter;
+
+use sea_orm::{ActiveModelTrait, IntoActiveModel};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{Game, GameKind, GameServer, ReservationOptions, Scrim},
+    error::BotError,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct QuickhostCommand {
+    /// The date/time to schedule the scrim for.
+    #[command(autocomplete)]
+    date_time: OffsetDateTime,
+}
+
+impl QuickhostCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild.ensure_time_open(&tx, self.date_time).await?;
+
+        guild.validate_schedule_time(self.date_time)?;
+
+        let overlap_warning = guild
+            .check_overlap(&tx, self.date_time, GameKind::Scrim, None)
+            .await?;
+
+        let mut game = Game {
+            guild_id: guild.id,
+            timestamp: self.date_time,
+            server: GameServer::default(),
+            details: Scrim {
+                opponent_user_id: None,
+                opponent_name: None,
+                opponent_contact: None,
+                game_format: guild.game_format.ok_or(BotError::NoGameFormat)?,
+                maps: guild.default_maps.clone().unwrap_or_default(),
+                result: None,
+            },
+            reservation_options: ReservationOptions::new(None, None),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
+        };
+
+        game.create_reservation(&guild.serveme_account()?, None)
+            .await?;
+
+        let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
+
+        let embed = game.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        game.create_discord_event(ctx, &guild, &bot.db).await;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(
+                    iter::once(success_embed("Scrim slot scheduled."))
+                        .chain(overlap_warning)
+                        .chain(iter::once(embed))
+                        .collect(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl QuickhostCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::DateTime { date_time } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_times(ctx, interaction, tx, &date_time)
+            .await
+    }
+}
@@ -0,0 +1,143 @@
+use std::iter;
+
+use sea_orm::{ColumnTrait, IntoActiveModel, QueryFilter};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::{
+        ReservationId,
+        game::{self, Game, GameKind, Scrim, ScrimOrMatch},
+    },
+    error::BotError,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct RehostCommand {
+    /// The scrim to host a server for.
+    #[command(autocomplete)]
+    scrim: OffsetDateTime,
+
+    /// An existing reservation to set up and use. If not provided, a new
+    /// reservation will be created.
+    #[command(autocomplete)]
+    reservation_id: Option<ReservationId>,
+
+    /// A specific serveme.tf server to reserve. If not provided, one will be
+    /// picked automatically.
+    #[command(autocomplete)]
+    server_id: Option<u32>,
+}
+
+impl RehostCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut scrim = guild.get_game::<Scrim>(&tx, self.scrim).await?;
+
+        if scrim.server.is_hosted() {
+            return Err(BotError::GameAlreadyHosted);
+        }
+
+        let overlap_warning = guild
+            .check_overlap(&tx, self.scrim, GameKind::Scrim, Some(self.scrim))
+            .await?;
+
+        scrim
+            .host(
+                &guild.serveme_account()?,
+                self.reservation_id,
+                self.server_id,
+            )
+            .await?;
+
+        let mut active_model = scrim.into_active_model();
+        active_model.reset_server();
+
+        let scrim = Game::<ScrimOrMatch>::try_from(active_model.update_versioned(&tx).await?)?;
+
+        let connect_info = scrim.connect_info_block(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new().embeds(
+                    iter::once(success_embed(format!("Scrim hosted.\n{connect_info}")))
+                        .chain(overlap_warning)
+                        .collect(),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl RehostCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Scrim { scrim, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_games::<Scrim>(
+                        ctx,
+                        interaction,
+                        tx,
+                        Some(guild.select_games::<Scrim>(None, |s| {
+                            s.filter(game::Column::ReservationId.is_null())
+                        })),
+                        &scrim,
+                    )
+                    .await
+            }
+            Self::ReservationId { reservation_id, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_reservations::<Scrim>(
+                        ctx,
+                        interaction,
+                        tx,
+                        |r| !r.status.is_ended(),
+                        &reservation_id,
+                    )
+                    .await
+            }
+            Self::ServerId {
+                server_id, scrim, ..
+            } => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_servers(
+                        ctx,
+                        interaction,
+                        GameKind::Scrim,
+                        scrim.into_value(),
+                        &server_id,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use sea_orm::{ActiveModelTrait, IntoActiveModel, QueryFilter};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::{
+        ScrimOutcome, ScrimResult,
+        game::{self, Game, GameDetails, Scrim},
+    },
+    error::BotError,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct ResultCommand {
+    /// The scrim to record the result of.
+    #[command(autocomplete)]
+    scrim: OffsetDateTime,
+
+    /// The outcome of the scrim. If left empty, this clears the result.
+    outcome: Option<ScrimOutcome>,
+
+    /// The number of rounds your team won.
+    our_score: Option<u32>,
+
+    /// The number of rounds the opposing team won.
+    their_score: Option<u32>,
+}
+
+impl ResultCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let score = match (self.our_score, self.their_score) {
+            (Some(ours), Some(theirs)) => Some((ours, theirs)),
+            (None, None) => None,
+            _ => return Err(BotError::InvalidScrimResult),
+        };
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut scrim = guild.get_game::<Scrim>(&tx, self.scrim).await?;
+
+        scrim.details.result = self.outcome.map(|outcome| ScrimResult { outcome, score });
+
+        let mut active_model = scrim.into_active_model();
+        active_model.reset(game::Column::ScrimResult);
+
+        let game = active_model.update_versioned(&tx).await?;
+
+        let embed = Game::try_from(game)?.embed(&guild).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embeds(vec![success_embed("Scrim result updated."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ResultCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Scrim { scrim, .. } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let selector = game::Entity::find_past(guild.id)
+            .filter(Scrim::filter_expr())
+            .into_partial_model();
+
+        guild
+            .autocomplete_games::<Scrim>(ctx, interaction, tx, Some(selector), &scrim)
+            .await
+    }
+}
@@ -0,0 +1,186 @@
+use std::iter;
+
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, IntoActiveModel};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    Bot, BotResult,
+    entities::{
+        GameFormat, MapList, OpponentUserId,
+        game::{Game, GameKind, GameServer, ReservationOptions, Scrim},
+        series,
+    },
+    error::BotError,
+    utils::success_embed,
+};
+
+/// How far apart the games of a series are scheduled.
+const GAME_INTERVAL: Duration = Duration::hours(2);
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct SeriesCommand {
+    /// How many games the series is a best of (1, 3 or 5).
+    n: u8,
+
+    /// The date/time to schedule the first game for.
+    #[command(autocomplete)]
+    date_time: OffsetDateTime,
+
+    /// Opposing team's contacted team member. Enter their user ID if they
+    /// haven't been played before.
+    #[command(autocomplete)]
+    opponent: Option<OpponentUserId>,
+
+    /// Space-separated list of maps to be played in every game. Defaults to
+    /// the guild's default maps.
+    #[command(autocomplete)]
+    maps: Option<MapList>,
+
+    /// The game format of the series. Defaults to the guild's default game
+    /// format.
+    game_format: Option<GameFormat>,
+}
+
+impl SeriesCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        if !matches!(self.n, 1 | 3 | 5) {
+            return Err(BotError::InvalidSeriesLength);
+        }
+
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild.validate_schedule_time(self.date_time)?;
+
+        let timestamps = (0..self.n)
+            .map(|i| self.date_time + GAME_INTERVAL * i32::from(i))
+            .collect::<Vec<_>>();
+
+        let mut overlap_warning = None;
+
+        for &timestamp in &timestamps {
+            guild.ensure_time_open(&tx, timestamp).await?;
+
+            // one warning is enough, as the games are back to back.
+            if let Some(warning) = guild
+                .check_overlap(&tx, timestamp, GameKind::Scrim, None)
+                .await?
+            {
+                overlap_warning.get_or_insert(warning);
+            }
+        }
+
+        let game_format = self
+            .game_format
+            .or(guild.game_format)
+            .ok_or(BotError::NoGameFormat)?;
+
+        let serveme_account = &guild.serveme_account()?;
+
+        let series = series::ActiveModel {
+            guild_id: Set(guild.id),
+            n: Set(self.n.into()),
+            ..Default::default()
+        }
+        .insert(&tx)
+        .await?;
+
+        let mut embeds = iter::once(success_embed("Series scheduled."))
+            .chain(overlap_warning)
+            .collect::<Vec<_>>();
+
+        let mut games = Vec::new();
+
+        for timestamp in timestamps {
+            let mut game = Game {
+                guild_id: guild.id,
+                timestamp,
+                server: GameServer::default(),
+                details: Scrim {
+                    opponent_user_id: self.opponent,
+                    opponent_name: None,
+                    opponent_contact: None,
+                    game_format,
+                    maps: self
+                        .maps
+                        .clone()
+                        .or_else(|| guild.default_maps.clone())
+                        .unwrap_or_default(),
+                    result: None,
+                },
+                reservation_options: ReservationOptions::default(),
+                note: None,
+                version: 0,
+                series_id: Some(series.id),
+                credentials: None,
+                discord_event_id: None,
+                backup_reservation_id: None,
+            };
+
+            game.create_reservation(serveme_account, None).await?;
+
+            let game = Game::try_from(game.into_active_model().insert(&tx).await?)?;
+
+            embeds.push(game.embed(&guild).await?);
+
+            games.push(game);
+        }
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        for game in &games {
+            game.create_discord_event(ctx, &guild, &bot.db).await;
+        }
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embeds(embeds))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl SeriesCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::DateTime { date_time, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_times(ctx, interaction, tx, &date_time)
+                    .await
+            }
+            Self::Opponent { opponent, .. } => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_opponents(ctx, interaction, tx, &opponent)
+                    .await
+            }
+            Self::Maps {
+                maps, game_format, ..
+            } => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_maps(ctx, interaction, game_format.flatten().into_value(), &maps)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{Bot, BotResult, entities::game::Scrim};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct ShowCommand {
+    /// The scrim to get details of.
+    #[command(autocomplete)]
+    scrim: OffsetDateTime,
+}
+
+impl ShowCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let embed = guild
+            .get_game::<Scrim>(&tx, self.scrim)
+            .await?
+            .into_scrim_or_match()
+            .embed(&guild)
+            .await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl ShowCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Scrim { scrim } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<Scrim>(ctx, interaction, tx, None, &scrim)
+            .await
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use sea_orm::{QueryFilter, prelude::*};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse, Mentionable};
+use serenity_commands::SubCommand;
+
+use crate::{
+    Bot, BotResult,
+    entities::{
+        OpponentUserId, ScrimOutcome,
+        game::{self, Game, GameDetails, Scrim},
+    },
+    utils::embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct StatsCommand;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Record {
+    wins: u32,
+    losses: u32,
+    ties: u32,
+}
+
+impl Record {
+    const fn add(&mut self, outcome: ScrimOutcome) {
+        match outcome {
+            ScrimOutcome::Win => self.wins += 1,
+            ScrimOutcome::Loss => self.losses += 1,
+            ScrimOutcome::Tie => self.ties += 1,
+        }
+    }
+
+    const fn total(self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}W - {}L - {}T", self.wins, self.losses, self.ties)
+    }
+}
+
+impl StatsCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let scrims = guild
+            .find_related(game::Entity)
+            .filter(Scrim::filter_expr())
+            .filter(game::Column::ScrimResult.is_not_null())
+            .into_partial_model::<Game<Scrim>>()
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let mut overall = Record::default();
+        let mut per_opponent = HashMap::<OpponentUserId, Record>::new();
+
+        for scrim in scrims {
+            let Some(result) = scrim.details.result else {
+                continue;
+            };
+
+            overall.add(result.outcome);
+
+            if let Some(opponent) = scrim.details.opponent_user_id {
+                per_opponent
+                    .entry(opponent)
+                    .or_default()
+                    .add(result.outcome);
+            }
+        }
+
+        let mut per_opponent = per_opponent.into_iter().collect::<Vec<_>>();
+        per_opponent.sort_by_key(|(_, record)| std::cmp::Reverse(record.total()));
+
+        let mut lines = vec![format!("**Overall:** {overall}")];
+
+        if !per_opponent.is_empty() {
+            lines.push("\n**By Opponent:**".to_owned());
+
+            lines.extend(
+                per_opponent
+                    .into_iter()
+                    .take(25)
+                    .map(|(opponent, record)| format!("{}: {record}", opponent.mention())),
+            );
+        }
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embed(embed("📊 Scrim Record").description(lines.join("\n"))),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
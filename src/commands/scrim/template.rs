@@ -0,0 +1,169 @@
+use sea_orm::{ActiveValue::Set, EntityTrait, ModelTrait, QueryOrder, sea_query::OnConflict};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::{SubCommand, SubCommandGroup};
+
+use crate::{
+    Bot, BotResult,
+    entities::{GameFormat, MapList, scrim_template},
+    error::BotError,
+    utils::{embed, success_embed},
+};
+
+#[derive(Debug, SubCommandGroup)]
+pub enum TemplateCommand {
+    /// Save a scrim template.
+    #[command(autocomplete)]
+    Save(TemplateSaveCommand),
+
+    /// List saved scrim templates.
+    List(TemplateListCommand),
+
+    /// Delete a scrim template.
+    #[command(autocomplete)]
+    Delete(TemplateDeleteCommand),
+}
+
+#[derive(Debug, SubCommand)]
+pub struct TemplateSaveCommand {
+    /// The name of the template. Saving over an existing name replaces it.
+    name: String,
+
+    /// The game format to use. Defaults to the guild's default game format.
+    game_format: Option<GameFormat>,
+
+    /// Space-separated list of maps to be played.
+    #[command(autocomplete)]
+    maps: Option<MapList>,
+}
+
+#[derive(Debug, SubCommand)]
+pub struct TemplateListCommand;
+
+#[derive(Debug, SubCommand)]
+pub struct TemplateDeleteCommand {
+    /// The template to delete.
+    #[command(autocomplete)]
+    name: String,
+}
+
+impl TemplateCommand {
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Save(_) | Self::Delete(_) => true,
+            Self::List(_) => false,
+        }
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let embed = match self {
+            Self::Save(cmd) => {
+                let game_format = cmd
+                    .game_format
+                    .or(guild.game_format)
+                    .ok_or(BotError::NoGameFormat)?;
+
+                scrim_template::Entity::insert(scrim_template::ActiveModel {
+                    guild_id: Set(guild.id),
+                    name: Set(cmd.name.clone()),
+                    game_format: Set(game_format),
+                    maps: Set(cmd.maps.unwrap_or_default()),
+                })
+                .on_conflict(
+                    OnConflict::columns([
+                        scrim_template::Column::GuildId,
+                        scrim_template::Column::Name,
+                    ])
+                    .update_columns([
+                        scrim_template::Column::GameFormat,
+                        scrim_template::Column::Maps,
+                    ])
+                    .to_owned(),
+                )
+                .exec(&tx)
+                .await?;
+
+                success_embed(format!("Template `{}` saved.", cmd.name))
+            }
+            Self::List(_) => {
+                let templates = guild
+                    .find_related(scrim_template::Entity)
+                    .order_by_asc(scrim_template::Column::Name)
+                    .all(&tx)
+                    .await?;
+
+                let description = if templates.is_empty() {
+                    "No templates saved.".to_owned()
+                } else {
+                    templates
+                        .iter()
+                        .map(|t| {
+                            format!(
+                                "**{}:** {} - {}",
+                                t.name,
+                                t.game_format,
+                                t.maps.list(true).unwrap_or_else(|| "No maps".into())
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                };
+
+                embed("📑 Scrim Templates").description(description)
+            }
+            Self::Delete(cmd) => {
+                guild
+                    .get_scrim_template(&tx, &cmd.name)
+                    .await?
+                    .delete(&tx)
+                    .await?;
+
+                success_embed(format!("Template `{}` deleted.", cmd.name))
+            }
+        };
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl TemplateCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Save(TemplateSaveCommandAutocomplete::Maps {
+                maps, game_format, ..
+            }) => {
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_maps(ctx, interaction, game_format.flatten().into_value(), &maps)
+                    .await
+            }
+            Self::Delete(TemplateDeleteCommandAutocomplete::Name { name }) => {
+                let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+                guild
+                    .autocomplete_scrim_templates(ctx, interaction, tx, &name)
+                    .await
+            }
+        }
+    }
+}
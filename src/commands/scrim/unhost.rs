@@ -0,0 +1,88 @@
+use sea_orm::{ColumnTrait, IntoActiveModel, QueryFilter};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::SubCommand;
+use time::OffsetDateTime;
+
+use crate::{
+    Bot, BotResult,
+    entities::game::{self, Game, GameServer, Scrim, ScrimOrMatch},
+    serveme::DeleteReservationRequest,
+    utils::success_embed,
+};
+
+#[derive(Clone, Debug, SubCommand)]
+pub struct UnhostCommand {
+    /// The scrim to give up the server of.
+    #[command(autocomplete)]
+    scrim: OffsetDateTime,
+}
+
+impl UnhostCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut scrim = guild.get_game::<Scrim>(&tx, self.scrim).await?;
+
+        let reservation_id = scrim.server.reservation_id()?;
+
+        scrim.server = GameServer::Undecided;
+        scrim.credentials = None;
+
+        let mut active_model = scrim.into_active_model();
+        active_model.reset_server();
+
+        let scrim = Game::<ScrimOrMatch>::try_from(active_model.update_versioned(&tx).await?)?;
+
+        // the row is updated first so that a concurrent edit stops the
+        // reservation from being deleted.
+        DeleteReservationRequest::send(&guild.serveme_account()?, reservation_id).await?;
+
+        let embed = scrim.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embeds(vec![success_embed("Scrim unhosted."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl UnhostCommandAutocomplete {
+    pub async fn autocomplete(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        let Self::Scrim { scrim } = self;
+
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        guild
+            .autocomplete_games::<Scrim>(
+                ctx,
+                interaction,
+                tx,
+                Some(guild.select_games::<Scrim>(None, |s| {
+                    s.filter(game::Column::ReservationId.is_not_null())
+                })),
+                &scrim,
+            )
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
+use serenity_commands::Command;
+
+use crate::{
+    Bot, BotResult,
+    rgl::{RglSeason, RglTeam},
+    utils::embed,
+};
+
+#[derive(Debug, Command)]
+pub enum SeasonCommand {
+    /// Show details of the team's current RGL.gg season.
+    Info,
+}
+
+impl SeasonCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Info => {
+                interaction.defer_ephemeral(ctx).await?;
+
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                let team = RglTeam::get(guild.rgl_team_id()?).await?;
+                let season = RglSeason::get(team.season_id).await?;
+
+                let maps = if season.maps.is_empty() {
+                    "Not announced".to_owned()
+                } else {
+                    season
+                        .maps
+                        .iter()
+                        .map(|m| format!("`{m}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+
+                let embed = embed(format!("🏆 {}", season.season_name))
+                    .url(team.season_id.url())
+                    .field("Format", season.format_name.to_string(), true)
+                    .field("Map Pool", maps, false);
+
+                interaction
+                    .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
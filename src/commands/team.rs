@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serenity::all::{CommandInteraction, Context, EditInteractionResponse, Mentionable, UserId};
+use serenity_commands::Command;
+
+use crate::{Bot, BotResult, entities::steam_mapping, rgl::RglTeam, utils::embed};
+
+#[derive(Debug, Command)]
+pub enum TeamCommand {
+    /// Show the team's RGL.gg roster.
+    Roster,
+}
+
+impl TeamCommand {
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+    ) -> BotResult {
+        match self {
+            Self::Roster => {
+                interaction.defer_ephemeral(ctx).await?;
+
+                let guild = bot.get_guild(interaction.guild_id).await?;
+
+                let team_id = guild.rgl_team_id()?;
+                let team = RglTeam::get(team_id).await?;
+
+                let discord_ids = steam_mapping::Entity::find()
+                    .filter(
+                        steam_mapping::Column::SteamId
+                            .is_in(team.players.iter().map(|p| i64::from(p.steam_id))),
+                    )
+                    .all(&bot.db)
+                    .await?
+                    .into_iter()
+                    .map(|mapping| (mapping.steam_id, UserId::from(mapping.user_id)))
+                    .collect::<HashMap<_, _>>();
+
+                let embed = embed(format!("👥 {}", team.name))
+                    .url(team_id.url())
+                    .fields(team.players.iter().take(25).map(|player| {
+                        let discord = discord_ids.get(&i64::from(player.steam_id)).map_or_else(
+                            || "No Discord link".to_owned(),
+                            |id| id.mention().to_string(),
+                        );
+
+                        (
+                            if player.is_leader {
+                                "👑 Leader"
+                            } else {
+                                "Player"
+                            },
+                            format!("[{}]({}) ({discord})", player.name, player.url()),
+                            true,
+                        )
+                    }));
+
+                interaction
+                    .edit_response(&ctx, EditInteractionResponse::new().embed(embed))
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,20 +1,60 @@
+use std::sync::{Arc, LazyLock};
+
+use moka::future::Cache;
+use sea_orm::IntoActiveModel;
 use serenity::all::{
-    ButtonStyle, ComponentInteraction, ComponentInteractionData, Context, CreateButton,
-    EditInteractionResponse,
+    ActionRowComponent, ButtonStyle, ComponentInteraction, ComponentInteractionData, Context,
+    CreateActionRow, CreateButton, CreateInputText, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateModal, EditInteractionResponse, GuildId,
+    InputTextStyle, InteractionId, Member, ModalInteraction, ModalInteractionData, Permissions,
 };
+use time::OffsetDateTime;
 
-use crate::{Bot, BotResult, error::BotError, utils::success_embed};
+use crate::{
+    Bot, BotResult,
+    commands::MAINTENANCE_MESSAGE,
+    entities::{
+        ConnectInfo, ReservationId,
+        game::{Game, GameServer, ScrimOrMatch},
+    },
+    error::BotError,
+    serveme::GetReservationRequest,
+    utils::{success_embed, warning_message},
+};
 
 #[derive(Debug, Clone)]
 pub enum AllComponents {
     Refresh(RefreshButton),
+    GamesPage(GamesPageButton),
+    GameRcon(GameRconButton),
+    ShowConnectInfoModal(ShowConnectInfoModalButton),
+    RconPage(RconPageButton),
+    DeleteGame(DeleteGameButton),
 }
 
 impl AllComponents {
     pub fn from_component_data(data: &ComponentInteractionData) -> BotResult<Self> {
         match data.custom_id.as_str() {
             RefreshButton::CUSTOM_ID => Ok(Self::Refresh(RefreshButton)),
-            _ => Err(BotError::InvalidComponentInteraction),
+            custom_id => GamesPageButton::from_custom_id(custom_id)
+                .map(Self::GamesPage)
+                .or_else(|| GameRconButton::from_custom_id(custom_id).map(Self::GameRcon))
+                .or_else(|| {
+                    ShowConnectInfoModalButton::from_custom_id(custom_id)
+                        .map(Self::ShowConnectInfoModal)
+                })
+                .or_else(|| RconPageButton::from_custom_id(custom_id).map(Self::RconPage))
+                .or_else(|| DeleteGameButton::from_custom_id(custom_id).map(Self::DeleteGame))
+                .ok_or(BotError::InvalidComponentInteraction),
+        }
+    }
+
+    /// Whether the component writes any data, and so must be rejected while
+    /// the bot is in maintenance mode.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::Refresh(_) | Self::ShowConnectInfoModal(_) | Self::DeleteGame(_) => true,
+            Self::GamesPage(_) | Self::GameRcon(_) | Self::RconPage(_) => false,
         }
     }
 
@@ -24,8 +64,62 @@ impl AllComponents {
         ctx: &Context,
         interaction: &ComponentInteraction,
     ) -> BotResult {
+        if bot.config.maintenance && self.is_mutating() {
+            interaction
+                .create_response(
+                    ctx,
+                    CreateInteractionResponse::Message(warning_message(MAINTENANCE_MESSAGE)),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
         match self {
             Self::Refresh(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::GamesPage(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::GameRcon(cmd) => cmd.run(bot, ctx, interaction).await,
+            Self::ShowConnectInfoModal(cmd) => cmd.run(ctx, interaction).await,
+            Self::RconPage(cmd) => cmd.run(ctx, interaction).await,
+            Self::DeleteGame(cmd) => cmd.run(bot, ctx, interaction).await,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AllModals {
+    ConnectInfo(ConnectInfoModal),
+}
+
+impl AllModals {
+    pub fn from_modal_data(data: &ModalInteractionData) -> BotResult<Self> {
+        ConnectInfoModal::from_modal_data(data)
+            .map(Self::ConnectInfo)
+            .ok_or(BotError::InvalidComponentInteraction)
+    }
+
+    /// Whether the modal writes any data, and so must be rejected while the
+    /// bot is in maintenance mode.
+    pub const fn is_mutating(&self) -> bool {
+        match self {
+            Self::ConnectInfo(_) => true,
+        }
+    }
+
+    pub async fn run(self, bot: &Bot, ctx: &Context, interaction: &ModalInteraction) -> BotResult {
+        if bot.config.maintenance && self.is_mutating() {
+            interaction
+                .create_response(
+                    ctx,
+                    CreateInteractionResponse::Message(warning_message(MAINTENANCE_MESSAGE)),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
+        match self {
+            Self::ConnectInfo(modal) => modal.run(bot, ctx, interaction).await,
         }
     }
 }
@@ -66,3 +160,478 @@ impl RefreshButton {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct DeleteGameButton {
+    guild_id: GuildId,
+    game_timestamp: OffsetDateTime,
+}
+
+impl DeleteGameButton {
+    const CUSTOM_ID_PREFIX: &'static str = "game:delete:confirm:";
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let (guild_id, game_timestamp) =
+            parse_game_key(custom_id.strip_prefix(Self::CUSTOM_ID_PREFIX)?)?;
+
+        Some(Self {
+            guild_id,
+            game_timestamp,
+        })
+    }
+
+    pub fn create<D>(game: &Game<D>) -> CreateButton {
+        CreateButton::new(format!("{}{}", Self::CUSTOM_ID_PREFIX, game_key(game)))
+            .label("🗑️ Delete")
+            .style(ButtonStyle::Danger)
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult {
+        if interaction.guild_id != Some(self.guild_id) {
+            return Err(BotError::InvalidComponentInteraction);
+        }
+
+        ensure_can_manage_guild(interaction.member.as_ref())?;
+
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let game = guild.delete_game(ctx, &tx, self.game_timestamp).await?;
+
+        let embed = game.embed(&guild).await?;
+
+        tx.commit().await?;
+
+        game.delete_discord_event(ctx).await;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embeds(vec![success_embed("Game cancelled."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GamesPageButton {
+    after: OffsetDateTime,
+}
+
+impl GamesPageButton {
+    const CUSTOM_ID_PREFIX: &'static str = "games-page:";
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let after = custom_id
+            .strip_prefix(Self::CUSTOM_ID_PREFIX)?
+            .parse()
+            .ok()
+            .and_then(|unix| OffsetDateTime::from_unix_timestamp(unix).ok())?;
+
+        Some(Self { after })
+    }
+
+    pub fn components(after: Option<OffsetDateTime>) -> Vec<CreateActionRow> {
+        after
+            .map(|after| {
+                CreateActionRow::Buttons(vec![
+                    CreateButton::new(format!(
+                        "{}{}",
+                        Self::CUSTOM_ID_PREFIX,
+                        after.unix_timestamp()
+                    ))
+                    .label("Next Page")
+                    .style(ButtonStyle::Secondary),
+                ])
+            })
+            .into_iter()
+            .collect()
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult {
+        let (guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let (embed, next) = guild.games_page(ctx, &tx, Some(self.after)).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(Self::components(next)),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GameRconButton {
+    guild_id: GuildId,
+    reservation_id: ReservationId,
+}
+
+impl GameRconButton {
+    const CUSTOM_ID_PREFIX: &'static str = "game:rcon:";
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let (guild_id, reservation_id) = custom_id
+            .strip_prefix(Self::CUSTOM_ID_PREFIX)?
+            .split_once(':')?;
+
+        Some(Self {
+            guild_id: GuildId::new(guild_id.parse().ok()?),
+            reservation_id: reservation_id.parse().ok()?,
+        })
+    }
+
+    pub fn create(guild_id: GuildId, reservation_id: ReservationId) -> CreateButton {
+        CreateButton::new(format!(
+            "{}{guild_id}:{reservation_id}",
+            Self::CUSTOM_ID_PREFIX
+        ))
+        .label("RCON Info")
+        .style(ButtonStyle::Secondary)
+    }
+
+    pub async fn run(
+        self,
+        bot: &Bot,
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+    ) -> BotResult {
+        if interaction.guild_id != Some(self.guild_id) {
+            return Err(BotError::InvalidComponentInteraction);
+        }
+
+        ensure_can_manage_guild(interaction.member.as_ref())?;
+
+        interaction.defer_ephemeral(ctx).await?;
+
+        let guild = bot.get_guild(interaction.guild_id).await?;
+
+        let reservation =
+            GetReservationRequest::send(&guild.serveme_account()?, self.reservation_id).await?;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .content(format!("```\n{}\n```", reservation.rcon_info())),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ShowConnectInfoModalButton {
+    guild_id: GuildId,
+    game_timestamp: OffsetDateTime,
+}
+
+impl ShowConnectInfoModalButton {
+    const CUSTOM_ID_PREFIX: &'static str = "connect-info:";
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let (guild_id, game_timestamp) =
+            parse_game_key(custom_id.strip_prefix(Self::CUSTOM_ID_PREFIX)?)?;
+
+        Some(Self {
+            guild_id,
+            game_timestamp,
+        })
+    }
+
+    pub fn create<D>(game: &Game<D>) -> CreateButton {
+        CreateButton::new(format!("{}{}", Self::CUSTOM_ID_PREFIX, game_key(game)))
+            .label("Enter Connect Info")
+            .style(ButtonStyle::Primary)
+    }
+
+    pub async fn run(self, ctx: &Context, interaction: &ComponentInteraction) -> BotResult {
+        if interaction.guild_id != Some(self.guild_id) {
+            return Err(BotError::InvalidComponentInteraction);
+        }
+
+        ensure_can_manage_guild(interaction.member.as_ref())?;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Modal(ConnectInfoModal::create(
+                    self.guild_id,
+                    self.game_timestamp,
+                )),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectInfoModal {
+    guild_id: GuildId,
+    game_timestamp: OffsetDateTime,
+    connect_info: String,
+}
+
+impl ConnectInfoModal {
+    const CUSTOM_ID_PREFIX: &'static str = "connect-info-modal:";
+    const INPUT_CUSTOM_ID: &'static str = "connect-info";
+
+    fn from_modal_data(data: &ModalInteractionData) -> Option<Self> {
+        let (guild_id, game_timestamp) =
+            parse_game_key(data.custom_id.strip_prefix(Self::CUSTOM_ID_PREFIX)?)?;
+
+        let connect_info = data
+            .components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                ActionRowComponent::InputText(input)
+                    if input.custom_id == Self::INPUT_CUSTOM_ID =>
+                {
+                    input.value.clone()
+                }
+                _ => None,
+            })?;
+
+        Some(Self {
+            guild_id,
+            game_timestamp,
+            connect_info,
+        })
+    }
+
+    fn create(guild_id: GuildId, game_timestamp: OffsetDateTime) -> CreateModal {
+        CreateModal::new(
+            format!(
+                "{}{guild_id}:{}",
+                Self::CUSTOM_ID_PREFIX,
+                game_timestamp.unix_timestamp()
+            ),
+            "Enter Connect Info",
+        )
+        .components(vec![CreateActionRow::InputText(
+            CreateInputText::new(InputTextStyle::Short, "Connect Info", Self::INPUT_CUSTOM_ID)
+                .placeholder(r#"connect <ip>:<port>; password "password""#),
+        )])
+    }
+
+    pub async fn run(self, bot: &Bot, ctx: &Context, interaction: &ModalInteraction) -> BotResult {
+        if interaction.guild_id != Some(self.guild_id) {
+            return Err(BotError::InvalidComponentInteraction);
+        }
+
+        ensure_can_manage_guild(interaction.member.as_ref())?;
+
+        let connect_info = self.connect_info.parse::<ConnectInfo>()?;
+
+        interaction.defer_ephemeral(ctx).await?;
+
+        let (mut guild, tx) = bot.get_guild_tx(interaction.guild_id).await?;
+
+        let mut game = guild
+            .get_game::<ScrimOrMatch>(&tx, self.game_timestamp)
+            .await?;
+
+        game.server = GameServer::Joined(connect_info);
+        game.credentials = None;
+        game.backup_reservation_id = None;
+
+        let mut active_model = game.into_active_model();
+        active_model.reset_server();
+
+        let game = Game::<ScrimOrMatch>::try_from(active_model.update_versioned(&tx).await?)?;
+
+        let embed = game.embed(&guild).await?;
+
+        guild.refresh_schedule(ctx, &tx).await?;
+
+        tx.commit().await?;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embeds(vec![success_embed("Connect info updated."), embed]),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RconPageButton {
+    output_id: InteractionId,
+    page: usize,
+}
+
+impl RconPageButton {
+    const CUSTOM_ID_PREFIX: &'static str = "rcon-page:";
+    const PAGE_LEN: usize = 1900;
+
+    fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let (output_id, page) = custom_id
+            .strip_prefix(Self::CUSTOM_ID_PREFIX)?
+            .split_once(':')?;
+
+        Some(Self {
+            output_id: output_id.parse().ok()?,
+            page: page.parse().ok()?,
+        })
+    }
+
+    fn output_cache() -> &'static Cache<InteractionId, Arc<[String]>> {
+        // interaction tokens expire after 15 minutes, after which the buttons
+        // can't be used anyway.
+        static CACHE: LazyLock<Cache<InteractionId, Arc<[String]>>> = LazyLock::new(|| {
+            Cache::builder()
+                .time_to_live(std::time::Duration::from_mins(15))
+                .build()
+        });
+
+        &CACHE
+    }
+
+    /// Split RCON output into pages and remember them, returning the first
+    /// page's message content and buttons.
+    pub async fn store(output_id: InteractionId, output: &str) -> (String, Vec<CreateActionRow>) {
+        let mut pages = Vec::<String>::new();
+
+        for line in output.lines() {
+            match pages.last_mut() {
+                Some(page) if page.len() + line.len() < Self::PAGE_LEN => {
+                    page.push('\n');
+                    page.push_str(line);
+                }
+                _ => {
+                    let mut line = line;
+
+                    while line.len() > Self::PAGE_LEN {
+                        let mut end = Self::PAGE_LEN;
+                        while !line.is_char_boundary(end) {
+                            end -= 1;
+                        }
+
+                        pages.push(line[..end].to_owned());
+                        line = &line[end..];
+                    }
+
+                    pages.push(line.to_owned());
+                }
+            }
+        }
+
+        let pages = Arc::<[String]>::from(pages);
+
+        Self::output_cache()
+            .insert(output_id, Arc::clone(&pages))
+            .await;
+
+        Self::page(output_id, &pages, 0)
+    }
+
+    fn page(
+        output_id: InteractionId,
+        pages: &[String],
+        page: usize,
+    ) -> (String, Vec<CreateActionRow>) {
+        let content = format!(
+            "Page {}/{}\n```\n{}\n```",
+            page + 1,
+            pages.len(),
+            pages[page]
+        );
+
+        let button = |page: usize, label: &str| {
+            CreateButton::new(format!("{}{output_id}:{page}", Self::CUSTOM_ID_PREFIX))
+                .label(label)
+                .style(ButtonStyle::Secondary)
+        };
+
+        let components = vec![CreateActionRow::Buttons(vec![
+            button(page.saturating_sub(1), "Previous").disabled(page == 0),
+            button(page + 1, "Next").disabled(page + 1 >= pages.len()),
+        ])];
+
+        (content, components)
+    }
+
+    pub async fn run(self, ctx: &Context, interaction: &ComponentInteraction) -> BotResult {
+        let pages = Self::output_cache()
+            .get(&self.output_id)
+            .await
+            .ok_or(BotError::RconOutputExpired)?;
+
+        let page = self.page.min(pages.len() - 1);
+
+        let (content, components) = Self::page(self.output_id, &pages, page);
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .content(content)
+                        .components(components),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Identify a game across guilds as `{guild_id}:{unix_timestamp}`.
+fn game_key<D>(game: &Game<D>) -> String {
+    format!(
+        "{}:{}",
+        GuildId::from(game.guild_id),
+        game.timestamp.unix_timestamp()
+    )
+}
+
+fn parse_game_key(key: &str) -> Option<(GuildId, OffsetDateTime)> {
+    let (guild_id, unix) = key.split_once(':')?;
+
+    Some((
+        GuildId::new(guild_id.parse().ok()?),
+        OffsetDateTime::from_unix_timestamp(unix.parse().ok()?).ok()?,
+    ))
+}
+
+fn ensure_can_manage_guild(member: Option<&Member>) -> BotResult {
+    let can_manage_guild = member
+        .and_then(|member| member.permissions)
+        .is_some_and(Permissions::manage_guild);
+
+    if can_manage_guild {
+        Ok(())
+    } else {
+        Err(BotError::MissingManageGuild)
+    }
+}
@@ -1,23 +1,211 @@
 use std::{
-    collections::HashSet,
+    collections::{BTreeMap, HashSet},
+    env,
+    ffi::OsStr,
     fmt::{self, Debug, Formatter},
+    fs, io,
+    net::SocketAddr,
+    path::{Path, PathBuf},
 };
 
 use serde::Deserialize;
+use serde_json::Value;
 use serenity::all::GuildId;
+use thiserror::Error;
+use toml_edit::DocumentMut;
+
+use crate::serveme;
 
 #[derive(Clone, Deserialize)]
 pub struct Config {
+    /// Required.
     pub discord_bot_token: String,
+    /// Required.
     pub database_url: String,
+    /// Guilds to register commands in. Defaults to registering them globally.
     pub guilds: Option<HashSet<GuildId>>,
+    /// Guilds the bot may be used in. Interactions from any other guild are
+    /// rejected. Defaults to allowing every guild.
+    pub allowed_guilds: Option<HashSet<GuildId>>,
+    /// Use compact logs. Defaults to `false`.
     #[serde(default)]
     pub production: bool,
+    /// How long before a game to post its reminder. Must be positive. Defaults
+    /// to 15 minutes.
+    #[serde(default = "default_reminder_minutes")]
+    pub reminder_minutes: i64,
+    /// How long to keep games after they've been played. Defaults to 90 days.
+    #[serde(default = "default_cleanup_max_age_days")]
+    pub cleanup_max_age_days: i64,
+    /// Where to serve the web API, if at all. Defaults to not serving it.
+    pub web_bind_addr: Option<SocketAddr>,
+    /// Bearer token for the web API. Defaults to allowing only `/health`.
+    pub web_secret: Option<String>,
+    /// The serveme.tf host used by guilds that haven't set their own. Defaults
+    /// to `na.serveme.tf`.
+    #[serde(default = "default_serveme_host")]
+    pub serveme_host: String,
+    /// Per-API-key serveme.tf request limit. Defaults to 60.
+    #[serde(default = "default_serveme_requests_per_minute")]
+    pub serveme_requests_per_minute: u32,
+    /// Reject commands that write data, e.g. while migrations are being run.
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub maintenance: bool,
+}
+
+const fn default_reminder_minutes() -> i64 {
+    15
+}
+
+const fn default_cleanup_max_age_days() -> i64 {
+    90
+}
+
+fn default_serveme_host() -> String {
+    serveme::DEFAULT_HOST.to_owned()
+}
+
+const fn default_serveme_requests_per_minute() -> u32 {
+    serveme::DEFAULT_REQUESTS_PER_MINUTE
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("could not read config file: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("invalid config file: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("invalid config file: {0}")]
+    Toml(#[from] toml_edit::TomlError),
+
+    #[error("config file must be a JSON object")]
+    NotAnObject,
+
+    #[error("config value `{0}` must be a string, number, boolean or array")]
+    NotAValue(String),
+
+    #[error("config file must end in .json or .toml: {}", .0.display())]
+    UnknownFormat(PathBuf),
+
+    #[error("--config needs a path to a config file")]
+    MissingPath,
+
+    #[error("missing required config value(s): {}", .0.join(", "))]
+    Missing(Vec<String>),
+
+    #[error("invalid config: {0}")]
+    Envy(#[from] envy::Error),
+
+    #[error("{0} must be positive")]
+    NotPositive(&'static str),
 }
 
 impl Config {
-    pub fn from_env() -> envy::Result<Self> {
-        envy::from_env()
+    const REQUIRED: &[&str] = &["discord_bot_token", "database_url"];
+
+    /// Read the config from environment variables, failing if any required
+    /// ones are missing or empty.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_values(
+            env::vars()
+                .map(|(key, value)| (key.to_lowercase(), value))
+                .collect(),
+        )
+    }
+
+    /// Read the config from a JSON or TOML file, picked by the file's
+    /// extension, with environment variables taking precedence over the file's
+    /// values.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+
+        let parse = match path.extension().and_then(OsStr::to_str) {
+            Some("json") => Self::json_values,
+            Some("toml") => Self::toml_values,
+            _ => return Err(ConfigError::UnknownFormat(path.to_owned())),
+        };
+
+        let mut values = parse(&fs::read_to_string(path)?)?;
+
+        values.extend(env::vars().map(|(key, value)| (key.to_lowercase(), value)));
+
+        Self::from_values(values)
+    }
+
+    fn json_values(contents: &str) -> Result<BTreeMap<String, String>, ConfigError> {
+        let Value::Object(file) = serde_json::from_str(contents)? else {
+            return Err(ConfigError::NotAnObject);
+        };
+
+        Ok(file
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = match value {
+                    Value::Null => return None,
+                    Value::String(s) => s,
+                    Value::Array(values) => values
+                        .iter()
+                        .map(|value| {
+                            value
+                                .as_str()
+                                .map_or_else(|| value.to_string(), ToOwned::to_owned)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(","),
+                    value => value.to_string(),
+                };
+
+                Some((key.to_lowercase(), value))
+            })
+            .collect())
+    }
+
+    fn toml_values(contents: &str) -> Result<BTreeMap<String, String>, ConfigError> {
+        fn to_string(value: &toml_edit::Value) -> String {
+            value
+                .as_str()
+                .map_or_else(|| value.to_string().trim().to_owned(), ToOwned::to_owned)
+        }
+
+        contents
+            .parse::<DocumentMut>()?
+            .iter()
+            .map(|(key, item)| {
+                let value = match item.as_value() {
+                    Some(toml_edit::Value::Array(values)) => {
+                        values.iter().map(to_string).collect::<Vec<_>>().join(",")
+                    }
+                    Some(value) => to_string(value),
+                    None => return Err(ConfigError::NotAValue(key.to_owned())),
+                };
+
+                Ok((key.to_lowercase(), value))
+            })
+            .collect()
+    }
+
+    fn from_values(values: BTreeMap<String, String>) -> Result<Self, ConfigError> {
+        let missing = Self::REQUIRED
+            .iter()
+            .copied()
+            .filter(|key| values.get(*key).is_none_or(|value| value.trim().is_empty()))
+            .map(str::to_uppercase)
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(ConfigError::Missing(missing));
+        }
+
+        let config = envy::from_iter::<_, Self>(values)?;
+
+        if config.reminder_minutes <= 0 {
+            return Err(ConfigError::NotPositive("REMINDER_MINUTES"));
+        }
+
+        Ok(config)
     }
 }
 
@@ -25,7 +213,89 @@ impl Debug for Config {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("Config")
             .field("guilds", &self.guilds)
+            .field("allowed_guilds", &self.allowed_guilds)
             .field("production", &self.production)
+            .field("reminder_minutes", &self.reminder_minutes)
+            .field("cleanup_max_age_days", &self.cleanup_max_age_days)
+            .field("web_bind_addr", &self.web_bind_addr)
+            .field("serveme_host", &self.serveme_host)
+            .field(
+                "serveme_requests_per_minute",
+                &self.serveme_requests_per_minute,
+            )
+            .field("maintenance", &self.maintenance)
             .finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(extra: &[(&str, &str)]) -> BTreeMap<String, String> {
+        [
+            ("discord_bot_token", "token"),
+            ("database_url", "postgres://"),
+        ]
+        .iter()
+        .chain(extra)
+        .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+        .collect()
+    }
+
+    #[test]
+    fn reminder_minutes_must_be_positive() {
+        assert_eq!(
+            Config::from_values(values(&[])).unwrap().reminder_minutes,
+            15
+        );
+        assert_eq!(
+            Config::from_values(values(&[("reminder_minutes", "90")]))
+                .unwrap()
+                .reminder_minutes,
+            90
+        );
+
+        for minutes in ["0", "-5"] {
+            assert!(matches!(
+                Config::from_values(values(&[("reminder_minutes", minutes)])),
+                Err(ConfigError::NotPositive("REMINDER_MINUTES"))
+            ));
+        }
+    }
+
+    #[test]
+    fn json_and_toml_files_read_the_same() {
+        let json = Config::json_values(
+            r#"{ "reminder_minutes": 30, "production": true, "guilds": ["1", 2], "web_secret": null }"#,
+        )
+        .unwrap();
+        let toml = Config::toml_values(
+            r#"
+            reminder_minutes = 30
+            production = true
+            guilds = ["1", 2]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(json, toml);
+        assert_eq!(toml["guilds"], "1,2");
+    }
+
+    #[test]
+    fn toml_tables_are_rejected() {
+        assert!(matches!(
+            Config::toml_values("[web]\nsecret = \"x\""),
+            Err(ConfigError::NotAValue(key)) if key == "web"
+        ));
+    }
+
+    #[test]
+    fn config_files_need_a_known_extension() {
+        assert!(matches!(
+            Config::from_file("config.yaml"),
+            Err(ConfigError::UnknownFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{BotResult, HTTP_CLIENT, entities::Map, serveme::ReservationResponse};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Demo {
+    pub id: u64,
+    pub server: String,
+    pub map: Map,
+    #[serde(with = "time::serde::timestamp")]
+    pub time: OffsetDateTime,
+    pub red: String,
+    pub blue: String,
+    pub red_score: u32,
+    pub blue_score: u32,
+}
+
+impl Demo {
+    /// The most pages of demos to look through for a single reservation.
+    const MAX_PAGES: u32 = 10;
+
+    /// Find the demos uploaded from a reservation's server during the
+    /// reservation.
+    ///
+    /// demos.tf can't filter by server, so every page of demos uploaded during
+    /// the reservation is fetched and filtered here.
+    pub async fn for_reservation(reservation: &ReservationResponse) -> BotResult<Vec<Self>> {
+        let server_name = reservation.server.name.to_lowercase();
+
+        let mut demos = Vec::new();
+
+        for page in 1..=Self::MAX_PAGES {
+            let page_demos = HTTP_CLIENT
+                .get("https://api.demos.tf/demos")
+                .query(&[
+                    ("after", reservation.starts_at.unix_timestamp()),
+                    ("before", reservation.ends_at.unix_timestamp()),
+                    ("page", page.into()),
+                ])
+                .send()
+                .await?
+                .error_for_status()?
+                .json::<Vec<Self>>()
+                .await?;
+
+            if page_demos.is_empty() {
+                break;
+            }
+
+            demos.extend(
+                page_demos
+                    .into_iter()
+                    .filter(|demo| demo.server.to_lowercase().contains(&server_name)),
+            );
+        }
+
+        Ok(demos)
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://demos.tf/{}", self.id)
+    }
+}
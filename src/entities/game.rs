@@ -2,31 +2,40 @@ use std::sync::Arc;
 
 use rand::distr::{Alphanumeric, SampleString};
 use sea_orm::{
-    ActiveValue::Unchanged, DbErr, FromQueryResult, IntoActiveModel, PartialModelTrait,
-    QueryResult, entity::prelude::*, sea_query::SimpleExpr,
+    ActiveValue::{Set, Unchanged},
+    DbErr, FromQueryResult, IntoActiveModel, PartialModelTrait, QueryOrder, QueryResult,
+    entity::prelude::*,
+    sea_query::SimpleExpr,
 };
+use serde::Serialize;
 use serenity::all::{
     AutocompleteChoice, CommandInteraction, Context, CreateAutocompleteResponse, CreateEmbed,
-    CreateInteractionResponse, FormattedTimestamp, FormattedTimestampStyle, Mentionable,
+    CreateInteractionResponse, CreateScheduledEvent, EditScheduledEvent, FormattedTimestamp,
+    FormattedTimestampStyle, Mentionable, ScheduledEventType,
 };
 use serenity_commands::BasicOption;
 use time::{Duration, OffsetDateTime};
+use tracing::{instrument, warn};
 
 use super::{
-    ConnectInfo, GameFormat, MapList, OpponentUserId, ReservationId, ServemeApiKey, TeamGuildId,
-    team_guild,
+    ConnectInfo, DiscordEventId, GameFormat, Map, MapList, MapVetoes, OpponentUserId,
+    ReservationId, ScrimResult, TeamGuildId, team_guild,
 };
 use crate::{
     BotResult,
+    calendar::Event,
     error::BotError,
     rgl::{RglMatch, RglMatchId, RglSeason, RglTeamId},
     serveme::{
         CreateReservationRequest, EditReservationRequest, FindServersRequest,
-        GetReservationRequest, MapsRequest, ReservationResponse,
+        GetReservationRequest, MapsRequest, ReservationResponse, ReservationStatus, ServemeAccount,
+        Server,
     },
-    utils::{OffsetDateTimeEtExt, time_string},
+    utils::time_string,
 };
 
+const UNAVAILABLE: &str = "```\nUnavailable\n```";
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "game")]
 pub struct Model {
@@ -40,6 +49,27 @@ pub struct Model {
     pub game_format: Option<GameFormat>,
     pub maps: Option<MapList>,
     pub rgl_match_id: Option<RglMatchId>,
+    pub scrim_result: Option<ScrimResult>,
+    pub enable_plugins: Option<bool>,
+    pub enable_demos_tf: Option<bool>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub map_vetoes: Option<MapVetoes>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub note: Option<String>,
+    pub version: i32,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub opponent_name: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub opponent_contact: Option<String>,
+    pub series_id: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub server_address: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub password: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub rcon: Option<String>,
+    pub discord_event_id: Option<DiscordEventId>,
+    pub backup_reservation_id: Option<ReservationId>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -62,6 +92,60 @@ impl Related<super::team_guild::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Entity {
+    /// The guild's games starting after `after`, earliest first.
+    pub fn find_after(guild_id: TeamGuildId, after: OffsetDateTime) -> Select<Self> {
+        Self::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .filter(Column::Timestamp.gt(after))
+            .order_by_asc(Column::Timestamp)
+    }
+
+    /// The guild's games that haven't started yet, earliest first.
+    pub fn find_upcoming(guild_id: TeamGuildId) -> Select<Self> {
+        Self::find_after(guild_id, OffsetDateTime::now_utc())
+    }
+
+    /// The guild's games that have already started, most recent first.
+    pub fn find_past(guild_id: TeamGuildId) -> Select<Self> {
+        Self::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .filter(Column::Timestamp.lt(OffsetDateTime::now_utc()))
+            .order_by_desc(Column::Timestamp)
+    }
+}
+
+impl ActiveModel {
+    /// Mark the game's server, and the credentials and backup reservation that
+    /// belong to it, as changed.
+    pub fn reset_server(&mut self) {
+        self.reset(Column::ReservationId);
+        self.reset(Column::ConnectInfo);
+        self.reset(Column::ServerAddress);
+        self.reset(Column::Password);
+        self.reset(Column::Rcon);
+        self.reset(Column::BackupReservationId);
+    }
+
+    /// Update the game, bumping its version. Fails with
+    /// [`BotError::ConcurrentModification`] if the game was changed since it
+    /// was loaded.
+    pub async fn update_versioned(mut self, db: &impl ConnectionTrait) -> BotResult<Model> {
+        let version = *self.version.as_ref();
+
+        self.version = Set(version + 1);
+
+        Entity::update(self)
+            .filter(Column::Version.eq(version))
+            .exec(db)
+            .await
+            .map_err(|error| match error {
+                DbErr::RecordNotUpdated => BotError::ConcurrentModification,
+                error => error.into(),
+            })
+    }
+}
+
 #[derive(DerivePartialModel)]
 #[sea_orm(entity = "Entity", from_query_result)]
 struct GameInner {
@@ -73,6 +157,20 @@ struct GameInner {
     game_format: Option<GameFormat>,
     maps: Option<MapList>,
     rgl_match_id: Option<RglMatchId>,
+    scrim_result: Option<ScrimResult>,
+    enable_plugins: Option<bool>,
+    enable_demos_tf: Option<bool>,
+    map_vetoes: Option<MapVetoes>,
+    note: Option<String>,
+    version: i32,
+    opponent_name: Option<String>,
+    opponent_contact: Option<String>,
+    series_id: Option<i32>,
+    server_address: Option<String>,
+    password: Option<String>,
+    rcon: Option<String>,
+    discord_event_id: Option<DiscordEventId>,
+    backup_reservation_id: Option<ReservationId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -81,6 +179,69 @@ pub struct Game<D = ScrimOrMatch> {
     pub timestamp: OffsetDateTime,
     pub server: GameServer,
     pub details: D,
+    pub reservation_options: ReservationOptions,
+    pub note: Option<String>,
+    /// Incremented on every update, so that concurrent edits can be detected.
+    pub version: i32,
+    /// The series the game is part of, if any.
+    pub series_id: Option<i32>,
+    /// The credentials the bot chose when it created the game's reservation.
+    pub credentials: Option<ServerCredentials>,
+    /// The Discord scheduled event created for the game, if any.
+    pub discord_event_id: Option<DiscordEventId>,
+    /// A second reservation starting when the first one ends, for matches that
+    /// may run long.
+    pub backup_reservation_id: Option<ReservationId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerCredentials {
+    pub connect_info: ConnectInfo,
+    pub rcon: String,
+}
+
+impl ServerCredentials {
+    fn from_parts(
+        server_address: Option<String>,
+        password: Option<String>,
+        rcon: Option<String>,
+    ) -> Option<Self> {
+        Some(Self {
+            connect_info: ConnectInfo {
+                ip_and_port: server_address?,
+                password: password?,
+            },
+            rcon: rcon?,
+        })
+    }
+
+    pub fn rcon_info(&self) -> String {
+        format!(
+            r#"rcon_address {}; rcon_password "{}""#,
+            self.connect_info.ip_and_port, self.rcon
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReservationOptions {
+    pub enable_plugins: bool,
+    pub enable_demos_tf: bool,
+}
+
+impl ReservationOptions {
+    pub fn new(enable_plugins: Option<bool>, enable_demos_tf: Option<bool>) -> Self {
+        Self {
+            enable_plugins: enable_plugins.unwrap_or(true),
+            enable_demos_tf: enable_demos_tf.unwrap_or(true),
+        }
+    }
+}
+
+impl Default for ReservationOptions {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
 }
 
 impl Game {
@@ -88,7 +249,7 @@ impl Game {
         &self,
         ctx: &Context,
         interaction: &CommandInteraction,
-        serveme_api_key: &ServemeApiKey,
+        serveme_account: &ServemeAccount,
         query: &str,
     ) -> BotResult {
         let query = query.trim().to_lowercase();
@@ -105,7 +266,7 @@ impl Game {
             .collect::<Vec<_>>();
 
         if choices.is_empty() {
-            choices = MapsRequest::send(serveme_api_key, Some(self.details.game_format().await?))
+            choices = MapsRequest::send(serveme_account, Some(self.details.game_format().await?))
                 .await?
                 .iter()
                 .map(ToString::to_string)
@@ -127,30 +288,31 @@ impl Game {
         Ok(())
     }
 
+    #[allow(clippy::too_many_lines)]
+    /// The game's embed. RCON details are left out, since the embed may be
+    /// shown to anyone; see [`Self::rcon_block`].
     pub async fn embed(&self, guild: &team_guild::Model) -> BotResult<CreateEmbed> {
-        let description = self
-            .server
-            .connect_info_block(guild.serveme_api_key.as_ref())
-            .await?;
-        let title = format!(
-            "{} **{}:** {}",
-            self.details.emoji(),
-            self.details.name(),
-            self.timestamp.string_et()
-        );
+        let description = match self.connect_info_block(guild).await {
+            Ok(block) => block,
+            Err(error) => {
+                warn!(?error, "failed to get connect info");
+                UNAVAILABLE.to_owned()
+            }
+        };
+        let title = self.details.title(guild, self.timestamp);
 
         let mut fields = vec![];
 
         if self.server.is_hosted() {
-            let reservation = self.get_reservation(guild.serveme_api_key()?).await?;
-            fields.extend([
-                (
-                    "RCON",
-                    format!("```\n{}\n```", reservation.rcon_info()),
-                    false,
-                ),
-                ("STV", reservation.stv_connect_info().code_block(), false),
-            ]);
+            let stv = match self.try_get_reservation(guild).await {
+                Ok(reservation) => reservation.stv_connect_info().code_block(),
+                Err(error) => {
+                    warn!(?error, "failed to get reservation for STV info");
+                    UNAVAILABLE.to_owned()
+                }
+            };
+
+            fields.push(("STV", stv, false));
         }
 
         fields.extend([
@@ -176,15 +338,24 @@ impl Game {
 
         match &self.details {
             ScrimOrMatch::Scrim(scrim) => {
-                if let Some(opponent) = scrim.opponent_user_id {
-                    fields.push(("Opponent", opponent.mention().to_string(), true));
+                if let Some(opponent) = scrim.opponent_details() {
+                    fields.push(("Opponent", opponent, true));
+                }
+
+                if let Some(contact) = &scrim.opponent_contact {
+                    fields.push(("Opponent Contact", contact.clone(), true));
                 }
 
                 fields.push(("Game Format", scrim.game_format.to_string(), true));
+
+                if let Some(result) = scrim.result {
+                    fields.push(("Result", result.description(), true));
+                }
             }
             ScrimOrMatch::Match(match_) => {
                 let rgl_match = RglMatch::get(match_.rgl_match_id).await?;
-                let opponent = rgl_match.opponent_team(guild.rgl_team_id()?)?;
+                let result = rgl_match.opponent_team(guild.rgl_team_id()?)?;
+                let opponent = &result.team;
 
                 fields.extend([
                     (
@@ -194,19 +365,61 @@ impl Game {
                     ),
                     (
                         "RGL Match",
-                        format!("[{}]({})", rgl_match.match_name, match_.rgl_match_id.url(),),
+                        format!("[{}]({})", rgl_match.match_name, match_.rgl_match_id.url()),
                         true,
                     ),
                 ]);
+
+                if let Some((ours, theirs)) = result.scores() {
+                    fields.push(("Score", format!("Us: {ours} – Them: {theirs}"), true));
+                }
+
+                if let Some(vetoes) = match_.vetoes.list() {
+                    fields.push(("Map Vetoes", vetoes, false));
+                }
             }
         }
 
+        if let Some(note) = &self.note {
+            fields.push(("Note", note.clone(), false));
+        }
+
         if let GameServer::Hosted(reservation_id) = self.server {
             fields.push((
                 "Reservation",
-                format!("[`{reservation_id}`]({})", reservation_id.url()),
+                format!(
+                    "[`{reservation_id}`]({})",
+                    reservation_id.url(guild.serveme_host())
+                ),
                 true,
             ));
+
+            if let Some(account) = guild.try_serveme_account() {
+                let status = match GetReservationRequest::send(&account, reservation_id).await {
+                    Ok(reservation) => reservation.status,
+                    Err(error) => {
+                        warn!(%reservation_id, ?error, "failed to fetch reservation status");
+                        ReservationStatus::Unknown
+                    }
+                };
+
+                fields.push((
+                    "Server Status",
+                    format!("{} {status}", status.emoji()),
+                    true,
+                ));
+            }
+
+            if let Some(backup_reservation_id) = self.backup_reservation_id {
+                fields.push((
+                    "Backup Reservation",
+                    format!(
+                        "[`{backup_reservation_id}`]({})",
+                        backup_reservation_id.url(guild.serveme_host())
+                    ),
+                    true,
+                ));
+            }
         }
 
         Ok(CreateEmbed::new()
@@ -215,27 +428,143 @@ impl Game {
             .fields(fields))
     }
 
+    pub async fn calendar_event(
+        &self,
+        ctx: &Context,
+        guild: &team_guild::Model,
+    ) -> BotResult<Event> {
+        let (starts_at, ends_at) = self.start_end_times();
+
+        let vs = self
+            .details
+            .opponent_string(ctx, guild.rgl_team_id)
+            .await?
+            .map(|opponent| format!(" vs. {opponent}"))
+            .unwrap_or_default();
+
+        let maps = self
+            .details
+            .maps()
+            .await?
+            .list(false)
+            .map(|maps| format!("Map(s): {maps}"));
+
+        // one unreachable reservation shouldn't sink the whole export, so the
+        // event just goes without connect info.
+        let connect_info = match self
+            .server
+            .connect_info(guild.try_serveme_account().as_ref())
+            .await
+        {
+            Ok(connect_info) => connect_info.map(|connect_info| connect_info.to_string()),
+            Err(error) => {
+                warn!(?self.guild_id, ?self.timestamp, ?error, "failed to get connect info for calendar");
+                None
+            }
+        };
+
+        let description = [connect_info, maps]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(Event {
+            uid: format!(
+                "{}-{}@scheduletf",
+                self.guild_id,
+                self.timestamp.unix_timestamp()
+            ),
+            starts_at,
+            ends_at,
+            summary: format!("{}{vs}", self.details.name()),
+            description: (!description.is_empty()).then_some(description),
+            url: match &self.details {
+                ScrimOrMatch::Scrim(_) => None,
+                ScrimOrMatch::Match(match_) => Some(match_.rgl_match_id.url()),
+            },
+        })
+    }
+
+    pub async fn export(&self, guild: &team_guild::Model) -> BotResult<GameExport> {
+        let opponent = match &self.details {
+            ScrimOrMatch::Scrim(scrim) => scrim
+                .opponent_name
+                .clone()
+                .or_else(|| scrim.opponent_user_id.map(|id| id.to_string())),
+            ScrimOrMatch::Match(match_) => {
+                let rgl_match = RglMatch::get(match_.rgl_match_id).await?;
+
+                Some(
+                    rgl_match
+                        .opponent_team(guild.rgl_team_id()?)?
+                        .team
+                        .team_name,
+                )
+            }
+        };
+
+        let (server, reservation_id) = match &self.server {
+            GameServer::Hosted(reservation_id) => ("hosted", Some(*reservation_id)),
+            GameServer::Joined(_) => ("joined", None),
+            GameServer::Undecided => ("undecided", None),
+        };
+
+        Ok(GameExport {
+            timestamp: self.timestamp,
+            kind: self.details.kind().prefix(),
+            opponent,
+            game_format: self.details.game_format().await?.to_string().to_lowercase(),
+            maps: self
+                .details
+                .maps()
+                .await?
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            server,
+            reservation_id,
+        })
+    }
+
     pub async fn schedule_entry(
         &self,
         guild: &team_guild::Model,
         include_connect: bool,
+        series_label: Option<&str>,
     ) -> BotResult<String> {
-        let time = time_string(self.timestamp.time_et());
+        let time = time_string(guild.to_local(self.timestamp).time());
+
+        let time = if guild.show_countdown() {
+            format!(
+                "{time} ({})",
+                FormattedTimestamp::new(
+                    self.timestamp.into(),
+                    Some(FormattedTimestampStyle::RelativeTime),
+                )
+            )
+        } else {
+            time
+        };
 
         let (kind, opponent) = match &self.details {
-            ScrimOrMatch::Scrim(scrim) => scrim.opponent_user_id.map_or_else(
-                || ("Looking for Scrim".to_owned(), None),
-                |opponent| ("Scrim".to_owned(), Some(opponent.mention().to_string())),
-            ),
+            ScrimOrMatch::Scrim(scrim) => (scrim.name().to_owned(), scrim.opponent_display()),
             ScrimOrMatch::Match(match_) => {
                 let rgl_team = guild.rgl_team_id()?;
 
                 let rgl_match = RglMatch::get(match_.rgl_match_id).await?;
 
-                let opponent = rgl_match.opponent_team(rgl_team)?;
+                let result = rgl_match.opponent_team(rgl_team)?;
+                let opponent = &result.team;
+
+                let badge = result
+                    .outcome()
+                    .filter(|_| self.timestamp < OffsetDateTime::now_utc())
+                    .map(|outcome| format!(" **({})**", outcome.letter()))
+                    .unwrap_or_default();
 
                 (
-                    format!("[Match]({})", match_.rgl_match_id.url()),
+                    format!("[Match]({}){badge}", match_.rgl_match_id.url()),
                     Some(format!(
                         "[{}]({})",
                         opponent.team_name,
@@ -257,69 +586,340 @@ impl Game {
             .map(|maps| format!(" - {maps}"))
             .unwrap_or_default();
 
+        let series = series_label
+            .map(|label| format!(" · {label}"))
+            .unwrap_or_default();
+
+        let note = self
+            .note
+            .as_ref()
+            .map(|note| format!(" *({note})*"))
+            .unwrap_or_default();
+
         let (whitespace, connect_info) = if include_connect {
-            (
-                ' ',
-                self.server
-                    .connect_info_block(guild.serveme_api_key.as_ref())
-                    .await?,
-            )
+            (' ', self.connect_info_block(guild).await?)
         } else {
             ('\n', String::new())
         };
 
         Ok(format!(
-            "{} **{time}:** {kind}{vs}{maps}{whitespace}{connect_info}",
-            self.details.emoji(),
+            "{} **{time}:** {kind}{series}{vs}{maps}{note}{whitespace}{connect_info}",
+            self.details.emoji(guild),
         ))
     }
 }
 
+/// The exported form of a game. Connect info and other secrets are left out.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameExport {
+    #[serde(with = "time::serde::iso8601")]
+    timestamp: OffsetDateTime,
+    kind: &'static str,
+    opponent: Option<String>,
+    game_format: String,
+    maps: Vec<String>,
+    server: &'static str,
+    reservation_id: Option<ReservationId>,
+}
+
 impl<D: GameDetails> Game<D> {
-    fn start_end_times(&self) -> (OffsetDateTime, OffsetDateTime) {
-        (
-            self.timestamp - Duration::minutes(15),
-            self.timestamp + self.details.kind().duration() + Duration::minutes(15),
-        )
+    pub fn into_scrim_or_match(self) -> Game {
+        Game {
+            guild_id: self.guild_id,
+            timestamp: self.timestamp,
+            server: self.server,
+            details: self.details.into(),
+            reservation_options: self.reservation_options,
+            note: self.note,
+            version: self.version,
+            series_id: self.series_id,
+            credentials: self.credentials,
+            discord_event_id: self.discord_event_id,
+            backup_reservation_id: self.backup_reservation_id,
+        }
+    }
+
+    /// Create a Discord scheduled event for the game and save its id. This is
+    /// only called once the game has been committed, so that a game that fails
+    /// to save never leaves an event behind. Failures are logged rather than
+    /// returned, since the event is only a mirror of the game.
+    pub async fn create_discord_event(
+        &self,
+        ctx: &Context,
+        guild: &team_guild::Model,
+        db: &impl ConnectionTrait,
+    ) {
+        let vs = match self.details.opponent_string(ctx, guild.rgl_team_id).await {
+            Ok(opponent) => opponent
+                .map(|opponent| format!(" vs. {opponent}"))
+                .unwrap_or_default(),
+            Err(error) => {
+                warn!(?error, "failed to get opponent for scheduled event");
+                String::new()
+            }
+        };
+
+        let end_time = self.timestamp + self.details.kind().duration();
+
+        let event = match self
+            .guild_id
+            .create_scheduled_event(
+                ctx,
+                CreateScheduledEvent::new(
+                    ScheduledEventType::External,
+                    format!("{}{vs}", self.details.name()),
+                    self.timestamp,
+                )
+                .end_time(end_time)
+                .location("Team Fortress 2"),
+            )
+            .await
+        {
+            Ok(event) => event,
+            Err(error) => {
+                warn!(?error, "failed to create scheduled event");
+                return;
+            }
+        };
+
+        // only the event ID is set, so the game's version is left alone.
+        let res = Entity::update_many()
+            .col_expr(
+                Column::DiscordEventId,
+                Expr::value(DiscordEventId::from(event.id)),
+            )
+            .filter(Column::GuildId.eq(self.guild_id))
+            .filter(Column::Timestamp.eq(self.timestamp))
+            .exec(db)
+            .await;
+
+        if let Err(error) = res {
+            warn!(event_id = ?event.id, ?error, "failed to save scheduled event");
+
+            if let Err(error) = self.guild_id.delete_scheduled_event(ctx, event.id).await {
+                warn!(event_id = ?event.id, ?error, "failed to delete scheduled event");
+            }
+        }
+    }
+
+    /// Move the game's Discord scheduled event, if it has one, to the game's
+    /// timestamp. Failures are logged rather than returned, since the event is
+    /// only a mirror of the game.
+    pub async fn sync_discord_event(&self, ctx: &Context) {
+        let Some(event_id) = self.discord_event_id else {
+            return;
+        };
+
+        let end_time = self.timestamp + self.details.kind().duration();
+
+        match self
+            .guild_id
+            .edit_scheduled_event(
+                ctx,
+                *event_id,
+                EditScheduledEvent::new()
+                    .start_time(self.timestamp)
+                    .end_time(end_time),
+            )
+            .await
+        {
+            Ok(_) => {}
+            Err(error) => warn!(?event_id, ?error, "failed to edit scheduled event"),
+        }
+    }
+
+    /// Delete the game's Discord scheduled event, if it has one. Failures are
+    /// logged rather than returned.
+    pub async fn delete_discord_event(&self, ctx: &Context) {
+        let Some(event_id) = self.discord_event_id else {
+            return;
+        };
+
+        match self.guild_id.delete_scheduled_event(ctx, *event_id).await {
+            Ok(()) => {}
+            Err(error) => warn!(?event_id, ?error, "failed to delete scheduled event"),
+        }
+    }
+
+    /// Falls back to the credentials saved when the reservation was created
+    /// if serveme.tf can't be reached or no longer has the reservation.
+    pub async fn connect_info_block(&self, guild: &team_guild::Model) -> BotResult<String> {
+        match self
+            .server
+            .connect_info_block(guild.try_serveme_account().as_ref())
+            .await
+        {
+            Err(error) if self.server.is_hosted() => self
+                .credentials
+                .as_ref()
+                .map(|c| c.connect_info.code_block())
+                .ok_or(error),
+            block => block,
+        }
+    }
+
+    /// The RCON details of a hosted game, falling back to the saved
+    /// credentials if the reservation can't be fetched.
+    pub async fn rcon_block(&self, guild: &team_guild::Model) -> Option<String> {
+        if !self.server.is_hosted() {
+            return None;
+        }
+
+        let rcon_info = match self.try_get_reservation(guild).await {
+            Ok(reservation) => Some(reservation.rcon_info()),
+            Err(error) => {
+                warn!(?error, "failed to get reservation, using saved credentials");
+                self.credentials.as_ref().map(ServerCredentials::rcon_info)
+            }
+        };
+
+        Some(rcon_info.map_or_else(
+            || UNAVAILABLE.to_owned(),
+            |rcon_info| format!("```\n{rcon_info}\n```"),
+        ))
+    }
+
+    async fn try_get_reservation(
+        &self,
+        guild: &team_guild::Model,
+    ) -> BotResult<Arc<ReservationResponse>> {
+        self.get_reservation(&guild.serveme_account()?).await
+    }
+
+    pub fn start_end_times(&self) -> (OffsetDateTime, OffsetDateTime) {
+        self.details.kind().reservation_window(self.timestamp)
     }
 
     pub async fn get_reservation(
         &self,
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
     ) -> BotResult<Arc<ReservationResponse>> {
         let reservation_id = self.server.reservation_id()?;
 
-        GetReservationRequest::send(api_key, reservation_id).await
+        GetReservationRequest::send(account, reservation_id).await
     }
 
+    #[instrument(skip_all, fields(guild_id = %self.guild_id, timestamp = %self.timestamp))]
     pub async fn create_reservation(
         &mut self,
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
+        preferred_server_id: Option<u32>,
     ) -> BotResult<Arc<ReservationResponse>> {
         let (starts_at, ends_at) = self.start_end_times();
 
-        let servers = FindServersRequest { starts_at, ends_at }
-            .send(api_key)
+        let reservation = self
+            .reserve(account, preferred_server_id, starts_at, ends_at)
             .await?;
 
-        let server_id = servers
-            .servers
-            .iter()
-            .find(|server| {
-                server.ip_and_port.starts_with("chi") || server.ip_and_port.starts_with("ks")
-            })
-            .ok_or(BotError::NoServemeServers)?
-            .id;
+        self.server = GameServer::Hosted(reservation.id);
+        self.credentials = Some(ServerCredentials {
+            connect_info: reservation.connect_info(),
+            rcon: reservation.rcon.clone(),
+        });
+
+        Ok(reservation)
+    }
+
+    /// Create a second reservation for a hosted match, starting when its
+    /// first reservation ends, in case the match runs long.
+    #[instrument(skip_all, fields(guild_id = %self.guild_id, timestamp = %self.timestamp))]
+    pub async fn create_backup_reservation(
+        &mut self,
+        account: &ServemeAccount,
+        preferred_server_id: Option<u32>,
+    ) -> BotResult<Arc<ReservationResponse>> {
+        if !self.server.is_hosted() {
+            return Err(BotError::GameNotHosted);
+        }
+
+        if self.backup_reservation_id.is_some() {
+            return Err(BotError::BackupReservationExists);
+        }
+
+        let (_, starts_at) = self.start_end_times();
+        let ends_at = starts_at + self.details.kind().duration();
 
-        let kind = self.details.kind();
+        let reservation = self
+            .reserve(account, preferred_server_id, starts_at, ends_at)
+            .await?;
+
+        self.backup_reservation_id = Some(reservation.id);
+
+        Ok(reservation)
+    }
+
+    async fn reserve(
+        &self,
+        account: &ServemeAccount,
+        preferred_server_id: Option<u32>,
+        starts_at: OffsetDateTime,
+        ends_at: OffsetDateTime,
+    ) -> BotResult<Arc<ReservationResponse>> {
+        let (request, _) = self
+            .reservation_request(account, preferred_server_id, starts_at, ends_at)
+            .await?;
+
+        let reservation = request.send(account).await;
+
+        // the server may have been taken since the search was cached, so make
+        // the next attempt search again.
+        if reservation.is_err() {
+            FindServersRequest { starts_at, ends_at }
+                .invalidate(account)
+                .await;
+        }
+
+        reservation
+    }
+
+    /// Work out the reservation the game would get for the given window,
+    /// along with the server it would be on, without creating it.
+    pub async fn reservation_request(
+        &self,
+        account: &ServemeAccount,
+        preferred_server_id: Option<u32>,
+        starts_at: OffsetDateTime,
+        ends_at: OffsetDateTime,
+    ) -> BotResult<(CreateReservationRequest, Server)> {
+        let search = FindServersRequest { starts_at, ends_at };
+
+        let servers = search.send(account).await?;
+
+        let server = if let Some(server_id) = preferred_server_id {
+            servers
+                .servers
+                .iter()
+                .find(|server| server.id == server_id)
+                .ok_or(BotError::ServemeServerUnavailable)?
+        } else {
+            servers
+                .servers
+                .iter()
+                .find(|server| account.prefers(server))
+                .ok_or(BotError::NoServemeServers)?
+        };
 
         let (first_map, server_config_id) = self
             .details
             .maps()
             .await?
-            .server_config(kind, self.details.game_format().await?);
+            .server_config(self.details.kind(), self.details.game_format().await?);
 
-        let prefix = kind.prefix();
+        let request = self.create_request(&search, server, first_map, server_config_id);
+
+        Ok((request, server.clone()))
+    }
+
+    /// The reservation to create on a server found by `search`. It covers
+    /// exactly the searched window, since the server may be busy outside it.
+    fn create_request(
+        &self,
+        search: &FindServersRequest,
+        server: &Server,
+        first_map: Option<Map>,
+        server_config_id: Option<u32>,
+    ) -> CreateReservationRequest {
+        let prefix = self.details.kind().prefix();
 
         let password = format!(
             "{prefix}.{}",
@@ -331,32 +931,46 @@ impl<D: GameDetails> Game<D> {
             Alphanumeric.sample_string(&mut rand::rng(), 32)
         );
 
-        let reservation = CreateReservationRequest {
-            starts_at,
-            ends_at,
+        CreateReservationRequest {
+            starts_at: search.starts_at,
+            ends_at: search.ends_at,
             first_map,
-            server_id,
+            server_id: server.id,
             password,
             rcon,
             server_config_id,
-            enable_plugins: true,
-            enable_demos_tf: true,
+            enable_plugins: self.reservation_options.enable_plugins,
+            enable_demos_tf: self.reservation_options.enable_demos_tf,
         }
-        .send(api_key)
-        .await?;
+    }
 
-        self.server = GameServer::Hosted(reservation.id);
+    /// Give the game a server: `reservation_id` is adopted and set up for the
+    /// game if provided, otherwise a new reservation is created.
+    pub async fn host(
+        &mut self,
+        account: &ServemeAccount,
+        reservation_id: Option<ReservationId>,
+        preferred_server_id: Option<u32>,
+    ) -> BotResult<Arc<ReservationResponse>> {
+        if let Some(reservation_id) = reservation_id {
+            self.server = GameServer::Hosted(reservation_id);
+            self.credentials = None;
+            self.backup_reservation_id = None;
 
-        Ok(reservation)
+            self.edit_reservation(account).await
+        } else {
+            self.create_reservation(account, preferred_server_id).await
+        }
     }
 
+    #[instrument(skip_all, fields(guild_id = %self.guild_id, timestamp = %self.timestamp))]
     pub async fn edit_reservation(
         &self,
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
     ) -> BotResult<Arc<ReservationResponse>> {
         let reservation_id = self.server.reservation_id()?;
 
-        let reservation = self.get_reservation(api_key).await?;
+        let reservation = self.get_reservation(account).await?;
 
         let (starts_at, ends_at) = self.start_end_times();
 
@@ -392,7 +1006,73 @@ impl<D: GameDetails> Game<D> {
             return Ok(reservation);
         }
 
-        req.send(api_key, reservation_id).await
+        req.send(account, reservation_id).await
+    }
+
+    /// Move the game's reservation, and its backup reservation if it has one,
+    /// to the game's current timestamp. Returns the reservations as they were,
+    /// so the move can be undone with [`Self::restore_reservations`].
+    #[instrument(skip_all, fields(guild_id = %self.guild_id, timestamp = %self.timestamp))]
+    pub async fn move_reservations(
+        &self,
+        account: &ServemeAccount,
+    ) -> BotResult<Vec<Arc<ReservationResponse>>> {
+        let mut previous = vec![self.get_reservation(account).await?];
+
+        self.edit_reservation(account).await?;
+
+        if let Some(backup_reservation_id) = self.backup_reservation_id {
+            let moved = async {
+                let backup = GetReservationRequest::send(account, backup_reservation_id).await?;
+
+                let (_, starts_at) = self.start_end_times();
+
+                EditReservationRequest {
+                    starts_at: Some(starts_at),
+                    ends_at: Some(starts_at + self.details.kind().duration()),
+                    ..Default::default()
+                }
+                .send(account, backup_reservation_id)
+                .await?;
+
+                BotResult::Ok(backup)
+            }
+            .await;
+
+            match moved {
+                Ok(backup) => previous.push(backup),
+                Err(error) => {
+                    self.restore_reservations(account, &previous).await;
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(previous)
+    }
+
+    /// Put reservations back the way they were before
+    /// [`Self::move_reservations`]. Failures are logged, since this only runs
+    /// while handling another error.
+    pub async fn restore_reservations(
+        &self,
+        account: &ServemeAccount,
+        previous: &[Arc<ReservationResponse>],
+    ) {
+        for reservation in previous {
+            if let Err(error) = EditReservationRequest::from(&**reservation)
+                .send(account, reservation.id)
+                .await
+            {
+                warn!(
+                    ?self.guild_id,
+                    ?self.timestamp,
+                    %reservation.id,
+                    ?error,
+                    "failed to restore reservation after a failed move"
+                );
+            }
+        }
     }
 }
 
@@ -414,6 +1094,10 @@ impl<D: GameDetails> TryFrom<Model> for Game<D> {
             model.game_format,
             model.maps,
             model.rgl_match_id,
+            model.scrim_result,
+            model.map_vetoes,
+            model.opponent_name,
+            model.opponent_contact,
         )
         .ok_or(BotError::InvalidGameDetails)?;
 
@@ -422,6 +1106,20 @@ impl<D: GameDetails> TryFrom<Model> for Game<D> {
             timestamp: model.timestamp,
             server,
             details,
+            reservation_options: ReservationOptions::new(
+                model.enable_plugins,
+                model.enable_demos_tf,
+            ),
+            note: model.note,
+            version: model.version,
+            series_id: model.series_id,
+            credentials: ServerCredentials::from_parts(
+                model.server_address,
+                model.password,
+                model.rcon,
+            ),
+            discord_event_id: model.discord_event_id,
+            backup_reservation_id: model.backup_reservation_id,
         })
     }
 }
@@ -456,6 +1154,10 @@ impl<D: GameDetails> FromQueryResult for Game<D> {
             inner.game_format,
             inner.maps,
             inner.rgl_match_id,
+            inner.scrim_result,
+            inner.map_vetoes,
+            inner.opponent_name,
+            inner.opponent_contact,
         )
         .ok_or(DbErr::Custom("game must be either scrim or match".into()))?;
 
@@ -464,6 +1166,20 @@ impl<D: GameDetails> FromQueryResult for Game<D> {
             timestamp: inner.timestamp,
             server,
             details,
+            reservation_options: ReservationOptions::new(
+                inner.enable_plugins,
+                inner.enable_demos_tf,
+            ),
+            note: inner.note,
+            version: inner.version,
+            series_id: inner.series_id,
+            credentials: ServerCredentials::from_parts(
+                inner.server_address,
+                inner.password,
+                inner.rcon,
+            ),
+            discord_event_id: inner.discord_event_id,
+            backup_reservation_id: inner.backup_reservation_id,
         })
     }
 }
@@ -491,33 +1207,71 @@ impl<D: GameDetails> IntoActiveModel<ActiveModel> for Game<D> {
             }
         }
 
-        let (opponent_user_id, game_format, maps, rgl_match_id) = self.details.into_parts();
+        let (
+            opponent_user_id,
+            game_format,
+            maps,
+            rgl_match_id,
+            scrim_result,
+            map_vetoes,
+            opponent_name,
+            opponent_contact,
+        ) = self.details.into_parts();
 
         active_model.opponent_user_id = Unchanged(opponent_user_id);
         active_model.game_format = Unchanged(game_format);
         active_model.maps = Unchanged(maps);
         active_model.rgl_match_id = Unchanged(rgl_match_id);
+        active_model.scrim_result = Unchanged(scrim_result);
+        active_model.map_vetoes = Unchanged(map_vetoes);
+        active_model.opponent_name = Unchanged(opponent_name);
+        active_model.opponent_contact = Unchanged(opponent_contact);
+        active_model.enable_plugins = Unchanged(Some(self.reservation_options.enable_plugins));
+        active_model.enable_demos_tf = Unchanged(Some(self.reservation_options.enable_demos_tf));
+        active_model.note = Unchanged(self.note);
+        active_model.version = Unchanged(self.version);
+        active_model.series_id = Unchanged(self.series_id);
+
+        let (server_address, password, rcon) = self
+            .credentials
+            .map(|c| (c.connect_info.ip_and_port, c.connect_info.password, c.rcon))
+            .map_or((None, None, None), |(a, p, r)| (Some(a), Some(p), Some(r)));
+
+        active_model.server_address = Unchanged(server_address);
+        active_model.password = Unchanged(password);
+        active_model.rcon = Unchanged(rcon);
+        active_model.discord_event_id = Unchanged(self.discord_event_id);
+        active_model.backup_reservation_id = Unchanged(self.backup_reservation_id);
 
         active_model
     }
 }
 
+type GameParts = (
+    Option<OpponentUserId>,
+    Option<GameFormat>,
+    Option<MapList>,
+    Option<RglMatchId>,
+    Option<ScrimResult>,
+    Option<MapVetoes>,
+    Option<String>,
+    Option<String>,
+);
+
 pub trait GameDetails: Into<ScrimOrMatch> + Sync + Sized {
+    #[allow(clippy::too_many_arguments)]
     fn from_parts(
         opponent_user_id: Option<OpponentUserId>,
         game_format: Option<GameFormat>,
         maps: Option<MapList>,
         rgl_match_id: Option<RglMatchId>,
+        scrim_result: Option<ScrimResult>,
+        map_vetoes: Option<MapVetoes>,
+        opponent_name: Option<String>,
+        opponent_contact: Option<String>,
     ) -> Option<Self>;
 
-    fn into_parts(
-        self,
-    ) -> (
-        Option<OpponentUserId>,
-        Option<GameFormat>,
-        Option<MapList>,
-        Option<RglMatchId>,
-    );
+    fn into_parts(self) -> GameParts;
 
     fn filter_expr() -> SimpleExpr;
 
@@ -525,7 +1279,16 @@ pub trait GameDetails: Into<ScrimOrMatch> + Sync + Sized {
 
     fn name(&self) -> &'static str;
 
-    fn emoji(&self) -> char;
+    fn emoji<'a>(&self, guild: &'a team_guild::Model) -> &'a str;
+
+    fn title(&self, guild: &team_guild::Model, timestamp: OffsetDateTime) -> String {
+        format!(
+            "{} {} — {}",
+            self.emoji(guild),
+            self.name(),
+            guild.format_datetime(timestamp)
+        )
+    }
 
     async fn opponent_string(
         &self,
@@ -550,34 +1313,40 @@ impl GameDetails for ScrimOrMatch {
         game_format: Option<GameFormat>,
         maps: Option<MapList>,
         rgl_match_id: Option<RglMatchId>,
+        scrim_result: Option<ScrimResult>,
+        map_vetoes: Option<MapVetoes>,
+        opponent_name: Option<String>,
+        opponent_contact: Option<String>,
     ) -> Option<Self> {
         match (opponent_user_id, game_format, maps, rgl_match_id) {
-            (opponent_user_id, Some(game_format), Some(maps), None) => Some(Self::Scrim(Scrim {
-                opponent_user_id,
-                game_format,
-                maps,
-            })),
-            (None, None, None, Some(rgl_match_id)) => Some(Self::Match(Match { rgl_match_id })),
+            (opponent_user_id, Some(game_format), Some(maps), None) if map_vetoes.is_none() => {
+                Some(Self::Scrim(Scrim {
+                    opponent_user_id,
+                    opponent_name,
+                    opponent_contact,
+                    game_format,
+                    maps,
+                    result: scrim_result,
+                }))
+            }
+            (None, None, None, Some(rgl_match_id))
+                if scrim_result.is_none()
+                    && opponent_name.is_none()
+                    && opponent_contact.is_none() =>
+            {
+                Some(Self::Match(Match {
+                    rgl_match_id,
+                    vetoes: map_vetoes.unwrap_or_default(),
+                }))
+            }
             _ => None,
         }
     }
 
-    fn into_parts(
-        self,
-    ) -> (
-        Option<OpponentUserId>,
-        Option<GameFormat>,
-        Option<MapList>,
-        Option<RglMatchId>,
-    ) {
+    fn into_parts(self) -> GameParts {
         match self {
-            Self::Scrim(scrim) => (
-                scrim.opponent_user_id,
-                Some(scrim.game_format),
-                Some(scrim.maps),
-                None,
-            ),
-            Self::Match(match_) => (None, None, None, Some(match_.rgl_match_id)),
+            Self::Scrim(scrim) => scrim.into_parts(),
+            Self::Match(match_) => match_.into_parts(),
         }
     }
 
@@ -599,10 +1368,10 @@ impl GameDetails for ScrimOrMatch {
         }
     }
 
-    fn emoji(&self) -> char {
+    fn emoji<'a>(&self, guild: &'a team_guild::Model) -> &'a str {
         match self {
-            Self::Scrim(scrim) => scrim.emoji(),
-            Self::Match(match_) => match_.emoji(),
+            Self::Scrim(scrim) => scrim.emoji(guild),
+            Self::Match(match_) => match_.emoji(guild),
         }
     }
 
@@ -635,8 +1404,37 @@ impl GameDetails for ScrimOrMatch {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Scrim {
     pub opponent_user_id: Option<OpponentUserId>,
+    pub opponent_name: Option<String>,
+    pub opponent_contact: Option<String>,
     pub game_format: GameFormat,
     pub maps: MapList,
+    pub result: Option<ScrimResult>,
+}
+
+impl Scrim {
+    /// Whether an opponent has been found, either as a Discord user or by name.
+    pub const fn has_opponent(&self) -> bool {
+        self.opponent_user_id.is_some() || self.opponent_name.is_some()
+    }
+
+    /// The opponent's team name alongside a mention of their contact, if either
+    /// is known.
+    fn opponent_details(&self) -> Option<String> {
+        match (&self.opponent_name, self.opponent_user_id) {
+            (Some(name), Some(user_id)) => Some(format!("{name} ({})", user_id.mention())),
+            (Some(name), None) => Some(name.clone()),
+            (None, Some(user_id)) => Some(user_id.mention().to_string()),
+            (None, None) => None,
+        }
+    }
+
+    /// The opponent's team name, falling back to a mention of their contact.
+    pub fn opponent_display(&self) -> Option<String> {
+        self.opponent_name.clone().or_else(|| {
+            self.opponent_user_id
+                .map(|opponent| opponent.mention().to_string())
+        })
+    }
 }
 
 impl From<Scrim> for ScrimOrMatch {
@@ -651,30 +1449,40 @@ impl GameDetails for Scrim {
         game_format: Option<GameFormat>,
         maps: Option<MapList>,
         rgl_match_id: Option<RglMatchId>,
+        scrim_result: Option<ScrimResult>,
+        map_vetoes: Option<MapVetoes>,
+        opponent_name: Option<String>,
+        opponent_contact: Option<String>,
     ) -> Option<Self> {
-        match (opponent_user_id, game_format, maps, rgl_match_id) {
-            (opponent_user_id, Some(game_format), Some(maps), None) => Some(Self {
+        match (
+            opponent_user_id,
+            game_format,
+            maps,
+            rgl_match_id,
+            map_vetoes,
+        ) {
+            (opponent_user_id, Some(game_format), Some(maps), None, None) => Some(Self {
                 opponent_user_id,
+                opponent_name,
+                opponent_contact,
                 game_format,
                 maps,
+                result: scrim_result,
             }),
             _ => None,
         }
     }
 
-    fn into_parts(
-        self,
-    ) -> (
-        Option<OpponentUserId>,
-        Option<GameFormat>,
-        Option<MapList>,
-        Option<RglMatchId>,
-    ) {
+    fn into_parts(self) -> GameParts {
         (
             self.opponent_user_id,
             Some(self.game_format),
             Some(self.maps),
             None,
+            self.result,
+            None,
+            self.opponent_name,
+            self.opponent_contact,
         )
     }
 
@@ -687,16 +1495,18 @@ impl GameDetails for Scrim {
     }
 
     fn name(&self) -> &'static str {
-        match self.opponent_user_id {
-            Some(_) => "Scrim",
-            None => "Looking for Scrim",
+        if self.has_opponent() {
+            "Scrim"
+        } else {
+            "Looking for Scrim"
         }
     }
 
-    fn emoji(&self) -> char {
-        match self.opponent_user_id {
-            Some(_) => '🎯',
-            None => '🔍',
+    fn emoji<'a>(&self, guild: &'a team_guild::Model) -> &'a str {
+        if self.has_opponent() {
+            guild.kind_emoji(GameKind::Scrim)
+        } else {
+            "🔍"
         }
     }
 
@@ -705,7 +1515,9 @@ impl GameDetails for Scrim {
         ctx: &Context,
         _: Option<RglTeamId>,
     ) -> BotResult<Option<String>> {
-        if let Some(opponent_user_id) = self.opponent_user_id {
+        if let Some(opponent_name) = &self.opponent_name {
+            Ok(Some(opponent_name.clone()))
+        } else if let Some(opponent_user_id) = self.opponent_user_id {
             let user = opponent_user_id.to_user(ctx).await?;
             Ok(Some(user.global_name.unwrap_or(user.name)))
         } else {
@@ -725,6 +1537,7 @@ impl GameDetails for Scrim {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Match {
     pub rgl_match_id: RglMatchId,
+    pub vetoes: MapVetoes,
 }
 
 impl From<Match> for ScrimOrMatch {
@@ -739,22 +1552,41 @@ impl GameDetails for Match {
         game_format: Option<GameFormat>,
         maps: Option<MapList>,
         rgl_match_id: Option<RglMatchId>,
+        scrim_result: Option<ScrimResult>,
+        map_vetoes: Option<MapVetoes>,
+        opponent_name: Option<String>,
+        opponent_contact: Option<String>,
     ) -> Option<Self> {
-        match (opponent_user_id, game_format, maps, rgl_match_id) {
-            (None, None, None, Some(rgl_match_id)) => Some(Self { rgl_match_id }),
+        match (
+            opponent_user_id,
+            game_format,
+            maps,
+            rgl_match_id,
+            scrim_result,
+        ) {
+            (None, None, None, Some(rgl_match_id), None)
+                if opponent_name.is_none() && opponent_contact.is_none() =>
+            {
+                Some(Self {
+                    rgl_match_id,
+                    vetoes: map_vetoes.unwrap_or_default(),
+                })
+            }
             _ => None,
         }
     }
 
-    fn into_parts(
-        self,
-    ) -> (
-        Option<OpponentUserId>,
-        Option<GameFormat>,
-        Option<MapList>,
-        Option<RglMatchId>,
-    ) {
-        (None, None, None, Some(self.rgl_match_id))
+    fn into_parts(self) -> GameParts {
+        (
+            None,
+            None,
+            None,
+            Some(self.rgl_match_id),
+            None,
+            (!self.vetoes.0.is_empty()).then_some(self.vetoes),
+            None,
+            None,
+        )
     }
 
     fn kind(&self) -> GameKind {
@@ -776,13 +1608,13 @@ impl GameDetails for Match {
     ) -> BotResult<Option<String>> {
         let rgl_match = RglMatch::get(self.rgl_match_id).await?;
 
-        let rgl_team = rgl_match.opponent_team(team_id.ok_or(BotError::NoRglTeam)?)?;
+        let result = rgl_match.opponent_team(team_id.ok_or(BotError::NoRglTeam)?)?;
 
-        Ok(Some(rgl_team.team_name))
+        Ok(Some(result.team.team_name))
     }
 
-    fn emoji(&self) -> char {
-        '🏆'
+    fn emoji<'a>(&self, guild: &'a team_guild::Model) -> &'a str {
+        guild.kind_emoji(GameKind::Match)
     }
 
     async fn maps(&self) -> BotResult<MapList> {
@@ -807,6 +1639,14 @@ pub enum GameKind {
 }
 
 impl GameKind {
+    /// The emoji shown for games of this kind, unless the guild overrides it.
+    pub const fn emoji(self) -> &'static str {
+        match self {
+            Self::Scrim => "🎮",
+            Self::Match => "🏆",
+        }
+    }
+
     pub const fn prefix(self) -> &'static str {
         match self {
             Self::Scrim => "scrim",
@@ -820,6 +1660,13 @@ impl GameKind {
             Self::Match => Duration::hours(2),
         }
     }
+
+    pub fn reservation_window(self, timestamp: OffsetDateTime) -> (OffsetDateTime, OffsetDateTime) {
+        (
+            timestamp - Duration::minutes(15),
+            timestamp + self.duration() + Duration::minutes(15),
+        )
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -846,20 +1693,27 @@ impl GameServer {
         }
     }
 
-    pub async fn connect_info_block(
+    pub async fn connect_info(
         &self,
-        serveme_api_key: Option<&ServemeApiKey>,
-    ) -> BotResult<String> {
-        let conn = match (self, serveme_api_key) {
-            (Self::Hosted(reservation_id), Some(api_key)) => Ok(Some(
-                GetReservationRequest::send(api_key, *reservation_id)
+        serveme_account: Option<&ServemeAccount>,
+    ) -> BotResult<Option<ConnectInfo>> {
+        match (self, serveme_account) {
+            (Self::Hosted(reservation_id), Some(account)) => Ok(Some(
+                GetReservationRequest::send(account, *reservation_id)
                     .await?
                     .connect_info(),
             )),
             (Self::Hosted(_), None) => Err(BotError::NoServemeApiKey),
             (Self::Joined(connect_info), _) => Ok(Some(connect_info.clone())),
             (Self::Undecided, _) => Ok(None),
-        }?;
+        }
+    }
+
+    pub async fn connect_info_block(
+        &self,
+        serveme_account: Option<&ServemeAccount>,
+    ) -> BotResult<String> {
+        let conn = self.connect_info(serveme_account).await?;
 
         Ok(conn.map_or_else(
             || "```\nNo connect info\n```".to_owned(),
@@ -893,3 +1747,140 @@ impl BasicOption for GameServer {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn reservation_window_pads_both_ends() {
+        let timestamp = datetime!(2024-10-16 21:00 -4);
+
+        assert_eq!(
+            GameKind::Scrim.reservation_window(timestamp),
+            (
+                datetime!(2024-10-16 20:45 -4),
+                datetime!(2024-10-16 22:15 -4)
+            )
+        );
+        assert_eq!(
+            GameKind::Match.reservation_window(timestamp),
+            (
+                datetime!(2024-10-16 20:45 -4),
+                datetime!(2024-10-16 23:15 -4)
+            )
+        );
+    }
+
+    #[test]
+    fn saved_credentials_need_every_part() {
+        let credentials = ServerCredentials::from_parts(
+            Some("1.2.3.4:27015".to_owned()),
+            Some("scrim.pw".to_owned()),
+            Some("scrim.rcon.pw".to_owned()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            credentials.rcon_info(),
+            r#"rcon_address 1.2.3.4:27015; rcon_password "scrim.rcon.pw""#
+        );
+
+        assert!(
+            ServerCredentials::from_parts(Some("1.2.3.4:27015".to_owned()), None, None).is_none()
+        );
+    }
+
+    fn guild() -> team_guild::Model {
+        team_guild::Model {
+            id: TeamGuildId::from(1),
+            rgl_team_id: None,
+            game_format: None,
+            schedule_channel_id: None,
+            schedule_message_id: None,
+            serveme_api_key: None,
+            scrim_division: None,
+            show_countdown: None,
+            min_notice_minutes: None,
+            game_voice_channel_id: None,
+            team_role_id: None,
+            reminder_channel_id: None,
+            timezone: None,
+            practice_config_id: None,
+            default_maps: None,
+            scrim_emoji: None,
+            match_emoji: None,
+            block_overlaps: None,
+            created_at: datetime!(2024-10-01 00:00 UTC),
+            serveme_host: None,
+        }
+    }
+
+    #[test]
+    fn reservations_cover_the_searched_window() {
+        let game = Game {
+            guild_id: TeamGuildId::from(1),
+            timestamp: datetime!(2024-10-16 21:00 -4),
+            server: GameServer::Undecided,
+            details: Match {
+                rgl_match_id: RglMatchId(1),
+                vetoes: MapVetoes::default(),
+            },
+            reservation_options: ReservationOptions::default(),
+            note: None,
+            version: 0,
+            series_id: None,
+            credentials: None,
+            discord_event_id: None,
+            backup_reservation_id: None,
+        };
+
+        let (starts_at, ends_at) = game.start_end_times();
+
+        // the server autocomplete searches by kind and time rather than game.
+        assert_eq!(
+            GameKind::Match.reservation_window(game.timestamp),
+            (starts_at, ends_at)
+        );
+
+        let search = FindServersRequest { starts_at, ends_at };
+        let server = Server {
+            id: 1,
+            name: "chi1".to_owned(),
+            ip: "1.2.3.4".to_owned(),
+            ip_and_port: "1.2.3.4:27015".to_owned(),
+        };
+
+        let request = game.create_request(&search, &server, None, None);
+
+        assert_eq!((request.starts_at, request.ends_at), (starts_at, ends_at));
+    }
+
+    #[test]
+    fn titles_name_the_kind_and_time() {
+        let guild = guild();
+        let timestamp = datetime!(2024-10-16 21:00 -4);
+        let when = guild.format_datetime(timestamp);
+
+        let scrim = Scrim {
+            opponent_user_id: None,
+            opponent_name: Some("froyotech".to_owned()),
+            opponent_contact: None,
+            game_format: GameFormat::Sixes,
+            maps: MapList::default(),
+            result: None,
+        };
+        let match_ = Match {
+            rgl_match_id: RglMatchId(1),
+            vetoes: MapVetoes::default(),
+        };
+
+        assert_eq!(scrim.title(&guild, timestamp), format!("🎮 Scrim — {when}"));
+        assert_eq!(
+            match_.title(&guild, timestamp),
+            format!("🏆 Match — {when}")
+        );
+    }
+}
@@ -11,20 +11,31 @@ use game::GameKind;
 use regex::Regex;
 use scraper::{Html, Selector};
 use sea_orm::{
-    ColIdx, DbErr, DeriveActiveEnum, DeriveValueType, EnumIter, QueryResult, TryFromU64,
-    TryGetError, TryGetable, Value,
+    ColIdx, DbErr, DeriveActiveEnum, DeriveValueType, EnumIter, FromJsonQueryResult, QueryResult,
+    TryFromU64, TryGetError, TryGetable, Value,
     sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr},
 };
 use serde::{Deserialize, Serialize};
 use serenity::all::{
     AutocompleteChoice, ChannelId, ChannelType, CommandDataOptionValue, CreateAutocompleteResponse,
-    CreateCommandOption, GuildId, MessageId, UserId,
+    CreateCommandOption, GuildId, MessageId, RoleId, ScheduledEventId, UserId,
 };
 use serenity_commands::BasicOption;
+use tracing::warn;
+use tz::TimeZoneRef;
 
-use crate::{BotResult, HTTP_CLIENT, error::BotError};
+use crate::{
+    BotResult, HTTP_CLIENT,
+    error::BotError,
+    serveme::{self, GetReservationRequest, ServemeAccount},
+};
 
 pub mod game;
+pub mod rcon_commands_log;
+pub mod rcon_macro;
+pub mod scrim_template;
+pub mod series;
+pub mod steam_mapping;
 pub mod team_guild;
 
 macro_rules! discord_id {
@@ -38,7 +49,7 @@ macro_rules! discord_id {
         }
     };
     ($Id:ident($DiscordId:ident)) => {
-        #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
         pub struct $Id(pub $DiscordId);
 
         impl std::ops::Deref for $Id {
@@ -118,6 +129,12 @@ discord_id!(TeamGuildId(GuildId));
 discord_id!(?ScheduleChannelId(ChannelId));
 discord_id!(?ScheduleMessageId(MessageId));
 discord_id!(?OpponentUserId(UserId));
+discord_id!(?GameVoiceChannelId(ChannelId));
+discord_id!(?TeamRoleId(RoleId));
+discord_id!(?ReminderChannelId(ChannelId));
+discord_id!(LinkedUserId(UserId));
+discord_id!(RconExecutorId(UserId));
+discord_id!(?DiscordEventId(ScheduledEventId));
 
 impl TryFromU64 for TeamGuildId {
     fn try_from_u64(n: u64) -> Result<Self, DbErr> {
@@ -125,6 +142,61 @@ impl TryFromU64 for TeamGuildId {
     }
 }
 
+impl TryFromU64 for LinkedUserId {
+    fn try_from_u64(n: u64) -> Result<Self, DbErr> {
+        i64::try_from_u64(n).map(Into::into)
+    }
+}
+
+impl OpponentUserId {
+    /// The opponent given either as a user, or as one of the guild's recent
+    /// opponents. Giving both is an error.
+    pub fn pick(user: Option<UserId>, recent: Option<Self>) -> BotResult<Option<Self>> {
+        match (user, recent) {
+            (Some(_), Some(_)) => Err(BotError::ConflictingOpponents),
+            (user, recent) => Ok(user.map(Into::into).or(recent)),
+        }
+    }
+}
+
+impl FromStr for OpponentUserId {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        let id = s
+            .strip_prefix("<@")
+            .and_then(|s| s.strip_suffix('>'))
+            .map_or(s, |s| s.trim_start_matches('!'));
+
+        id.parse::<u64>()
+            .ok()
+            .filter(|&id| id != 0)
+            .map(|id| Self(UserId::new(id)))
+            .ok_or(BotError::InvalidUserId)
+    }
+}
+
+impl BasicOption for OpponentUserId {
+    type Partial = String;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateCommandOption {
+        String::create_option(name, description)
+    }
+
+    fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
+        let value = String::from_value(value)?;
+
+        value
+            .parse()
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
+    }
+}
+
 impl BasicOption for ScheduleChannelId {
     type Partial = ChannelId;
 
@@ -142,6 +214,57 @@ impl BasicOption for ScheduleChannelId {
     }
 }
 
+impl BasicOption for GameVoiceChannelId {
+    type Partial = ChannelId;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> serenity::all::CreateCommandOption {
+        ChannelId::create_option(name, description).channel_types(vec![ChannelType::Voice])
+    }
+
+    fn from_value(
+        value: Option<&serenity::all::CommandDataOptionValue>,
+    ) -> serenity_commands::Result<Self> {
+        ChannelId::from_value(value).map(Self)
+    }
+}
+
+impl BasicOption for TeamRoleId {
+    type Partial = RoleId;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> serenity::all::CreateCommandOption {
+        RoleId::create_option(name, description)
+    }
+
+    fn from_value(
+        value: Option<&serenity::all::CommandDataOptionValue>,
+    ) -> serenity_commands::Result<Self> {
+        RoleId::from_value(value).map(Self)
+    }
+}
+
+impl BasicOption for ReminderChannelId {
+    type Partial = ChannelId;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> serenity::all::CreateCommandOption {
+        ChannelId::create_option(name, description).channel_types(vec![ChannelType::Text])
+    }
+
+    fn from_value(
+        value: Option<&serenity::all::CommandDataOptionValue>,
+    ) -> serenity_commands::Result<Self> {
+        ChannelId::from_value(value).map(Self)
+    }
+}
+
 #[derive(
     Clone, Debug, Copy, PartialEq, Eq, Hash, EnumIter, BasicOption, DeriveActiveEnum, Deserialize,
 )]
@@ -156,6 +279,18 @@ pub enum GameFormat {
 }
 
 impl GameFormat {
+    pub fn from_format_name(s: &str) -> Option<Self> {
+        if s.eq_ignore_ascii_case("sixes") {
+            Some(Self::Sixes)
+        } else if s.eq_ignore_ascii_case("highlander") {
+            Some(Self::Highlander)
+        } else {
+            warn!(format_name = s, "unknown game format name");
+
+            None
+        }
+    }
+
     #[allow(clippy::unreadable_literal)]
     pub const fn lfs_channel(self) -> ChannelId {
         match self {
@@ -170,6 +305,14 @@ impl GameFormat {
             Self::Highlander => 24,
         }
     }
+
+    /// The RGL whitelist shipped with the RGL server configs.
+    pub const fn rgl_whitelist(self) -> &'static str {
+        match self {
+            Self::Sixes => "cfg/rgl_whitelist_6s.txt",
+            Self::Highlander => "cfg/rgl_whitelist_HL.txt",
+        }
+    }
 }
 
 impl Display for GameFormat {
@@ -181,7 +324,9 @@ impl Display for GameFormat {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, BasicOption, DeriveValueType, Serialize, Deserialize)]
+#[derive(
+    Clone, Debug, PartialEq, Eq, Hash, BasicOption, DeriveValueType, Serialize, Deserialize,
+)]
 #[serde(transparent)]
 pub struct ServemeApiKey(pub String);
 
@@ -203,6 +348,117 @@ impl Nullable for ServemeApiKey {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, DeriveValueType)]
+pub struct ServemeHost(pub String);
+
+impl FromStr for ServemeHost {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let host = s
+            .trim()
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_end_matches('/')
+            .to_lowercase();
+
+        let valid = host == "serveme.tf"
+            || host.strip_suffix(".serveme.tf").is_some_and(|region| {
+                !region.is_empty()
+                    && region
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+
+        if valid {
+            Ok(Self(host))
+        } else {
+            Err(BotError::InvalidServemeHost)
+        }
+    }
+}
+
+impl Display for ServemeHost {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Nullable for ServemeHost {
+    fn null() -> Value {
+        String::null()
+    }
+}
+
+impl BasicOption for ServemeHost {
+    type Partial = String;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateCommandOption {
+        String::create_option(name, description)
+    }
+
+    fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
+        String::from_value(value)?
+            .parse()
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveValueType)]
+pub struct Timezone(pub String);
+
+impl Timezone {
+    pub fn time_zone(&self) -> TimeZoneRef<'static> {
+        tzdb::tz_by_name(&self.0).unwrap_or(tzdb::time_zone::america::NEW_YORK)
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        tzdb::TZ_NAMES
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(s))
+            .map(|name| Self((*name).to_owned()))
+            .ok_or(BotError::InvalidTimezone)
+    }
+}
+
+impl Display for Timezone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Nullable for Timezone {
+    fn null() -> Value {
+        String::null()
+    }
+}
+
+impl BasicOption for Timezone {
+    type Partial = String;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateCommandOption {
+        String::create_option(name, description)
+    }
+
+    fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
+        String::from_value(value)?
+            .parse()
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ConnectInfo {
     pub ip_and_port: String,
@@ -210,6 +466,16 @@ pub struct ConnectInfo {
 }
 
 impl ConnectInfo {
+    /// Look up the connect info of a serveme.tf reservation.
+    pub async fn from_reservation(
+        reservation_id: ReservationId,
+        account: &ServemeAccount,
+    ) -> BotResult<Self> {
+        let reservation = GetReservationRequest::send(account, reservation_id).await?;
+
+        Ok(reservation.connect_info())
+    }
+
     pub fn code_block(&self) -> String {
         format!("```\n{self}\n```")
     }
@@ -274,6 +540,267 @@ impl BasicOption for ConnectInfo {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, BasicOption)]
+#[option(option_type = "string")]
+pub enum ScrimOutcome {
+    #[option(name = "Win", value = "W")]
+    Win,
+    #[option(name = "Loss", value = "L")]
+    Loss,
+    #[option(name = "Tie", value = "T")]
+    Tie,
+}
+
+impl ScrimOutcome {
+    pub const fn letter(self) -> char {
+        match self {
+            Self::Win => 'W',
+            Self::Loss => 'L',
+            Self::Tie => 'T',
+        }
+    }
+}
+
+impl Display for ScrimOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Win => f.write_str("Win"),
+            Self::Loss => f.write_str("Loss"),
+            Self::Tie => f.write_str("Tie"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScrimResult {
+    pub outcome: ScrimOutcome,
+    pub score: Option<(u32, u32)>,
+}
+
+impl ScrimResult {
+    pub fn description(&self) -> String {
+        self.score.map_or_else(
+            || self.outcome.to_string(),
+            |(ours, theirs)| format!("{} ({ours}-{theirs})", self.outcome),
+        )
+    }
+}
+
+impl FromStr for ScrimResult {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (outcome, score) = s.split_once(' ').map_or((s, None), |(o, s)| (o, Some(s)));
+
+        let outcome = match outcome {
+            "W" => ScrimOutcome::Win,
+            "L" => ScrimOutcome::Loss,
+            "T" => ScrimOutcome::Tie,
+            _ => return Err(BotError::InvalidScrimResult),
+        };
+
+        let score = score
+            .map(|score| {
+                let (ours, theirs) = score.split_once('-')?;
+
+                Some((ours.parse().ok()?, theirs.parse().ok()?))
+            })
+            .map(|score| score.ok_or(BotError::InvalidScrimResult))
+            .transpose()?;
+
+        Ok(Self { outcome, score })
+    }
+}
+
+impl Display for ScrimResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.outcome.letter())?;
+
+        if let Some((ours, theirs)) = self.score {
+            write!(f, " {ours}-{theirs}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryGetable for ScrimResult {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &QueryResult,
+        idx: I,
+    ) -> Result<Self, sea_orm::TryGetError> {
+        <String as TryGetable>::try_get_by(res, idx).and_then(|s| {
+            s.parse::<Self>().map_err(|e| {
+                TryGetError::DbErr(DbErr::TryIntoErr {
+                    from: "String",
+                    into: "ScrimResult",
+                    source: e.into(),
+                })
+            })
+        })
+    }
+}
+
+impl From<ScrimResult> for Value {
+    fn from(source: ScrimResult) -> Self {
+        source.to_string().into()
+    }
+}
+
+impl ValueType for ScrimResult {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        <String as ValueType>::try_from(v).and_then(|s| s.parse::<Self>().map_err(|_| ValueTypeErr))
+    }
+
+    fn type_name() -> String {
+        stringify!(ScrimResult).to_owned()
+    }
+
+    fn column_type() -> ColumnType {
+        <String as ValueType>::column_type()
+    }
+
+    fn array_type() -> ArrayType {
+        <String as ValueType>::array_type()
+    }
+}
+
+impl Nullable for ScrimResult {
+    fn null() -> Value {
+        String::null()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, BasicOption, Serialize, Deserialize)]
+#[option(option_type = "string")]
+pub enum VetoTeam {
+    #[option(name = "Us", value = "us")]
+    Us,
+    #[option(name = "Them", value = "them")]
+    Them,
+}
+
+impl Display for VetoTeam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Us => f.write_str("Us"),
+            Self::Them => f.write_str("Them"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, BasicOption, Serialize, Deserialize)]
+#[option(option_type = "string")]
+pub enum VetoAction {
+    #[option(name = "Ban", value = "ban")]
+    Ban,
+    #[option(name = "Pick", value = "pick")]
+    Pick,
+}
+
+impl VetoAction {
+    pub const fn emoji(self) -> char {
+        match self {
+            Self::Ban => '🚫',
+            Self::Pick => '✅',
+        }
+    }
+}
+
+impl Display for VetoAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ban => f.write_str("ban"),
+            Self::Pick => f.write_str("pick"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MapVeto {
+    pub team: VetoTeam,
+    pub action: VetoAction,
+    pub map: Map,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, FromJsonQueryResult)]
+#[serde(transparent)]
+pub struct MapVetoes(pub Vec<MapVeto>);
+
+impl MapVetoes {
+    pub fn list(&self) -> Option<String> {
+        (!self.0.is_empty()).then(|| {
+            self.0
+                .iter()
+                .enumerate()
+                .map(|(i, veto)| {
+                    format!(
+                        "{}. {} **{}** {} `{}`",
+                        i + 1,
+                        veto.action.emoji(),
+                        veto.team,
+                        veto.action,
+                        veto.map
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JoinInfo {
+    ConnectInfo(ConnectInfo),
+    Reservation(ReservationId),
+}
+
+impl JoinInfo {
+    pub async fn connect_info(self, guild: &team_guild::Model) -> BotResult<ConnectInfo> {
+        match self {
+            Self::ConnectInfo(connect_info) => Ok(connect_info),
+            Self::Reservation(reservation_id) => {
+                ConnectInfo::from_reservation(reservation_id, &guild.serveme_account()?).await
+            }
+        }
+    }
+}
+
+impl FromStr for JoinInfo {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        static RESERVATION_URL: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^\s*(?:https?://)?(?:[a-z]+\.)?serveme\.tf/reservations/(\d+)").unwrap()
+        });
+
+        if let Some(captures) = RESERVATION_URL.captures(s) {
+            return captures[1].parse().map(Self::Reservation);
+        }
+
+        s.parse().map(Self::ConnectInfo)
+    }
+}
+
+impl BasicOption for JoinInfo {
+    type Partial = String;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateCommandOption {
+        String::create_option(name, description)
+    }
+
+    fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
+        let value = String::from_value(value)?;
+
+        value
+            .parse()
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
+    }
+}
+
 impl TryGetable for ConnectInfo {
     fn try_get_by<I: sea_orm::ColIdx>(
         res: &QueryResult,
@@ -339,20 +866,22 @@ impl Nullable for ConnectInfo {
 pub struct ReservationId(pub i32);
 
 impl ReservationId {
-    pub fn url(self) -> String {
-        format!("https://na.serveme.tf/reservations/{self}")
+    pub fn url(self, host: &str) -> String {
+        serveme::url(host, &format!("/reservations/{self}"))
     }
 
     pub async fn rcon_autocomplete_choices(
         self,
+        host: &str,
         query: &str,
     ) -> BotResult<CreateAutocompleteResponse> {
         static LI_SELECTOR: LazyLock<Selector> =
             LazyLock::new(|| Selector::parse("li").expect("static selector should be valid"));
 
         let html = HTTP_CLIENT
-            .get(format!(
-                "https://na.serveme.tf/rcon-autocomplete/{self}?query={query}"
+            .get(serveme::url(
+                host,
+                &format!("/rcon-autocomplete/{self}?query={query}"),
             ))
             .send()
             .await?
@@ -399,6 +928,31 @@ impl Nullable for ReservationId {
 pub struct MapList(pub Vec<Map>);
 
 impl MapList {
+    pub const MAX_MAPS: usize = 5;
+
+    /// A map list of at most `max` maps.
+    pub fn with_max(maps: Vec<Map>, max: usize) -> BotResult<Self> {
+        if maps.len() > max {
+            return Err(BotError::TooManyMaps {
+                count: maps.len(),
+                max,
+            });
+        }
+
+        Ok(Self(maps))
+    }
+
+    pub fn validate(&self) -> BotResult {
+        if self.len() > Self::MAX_MAPS {
+            return Err(BotError::TooManyMaps {
+                count: self.len(),
+                max: Self::MAX_MAPS,
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn autocomplete_choice<'a>(this: impl IntoIterator<Item = &'a Map>) -> AutocompleteChoice {
         let maps = this.into_iter().map(Map::as_str).collect::<Vec<_>>();
 
@@ -485,7 +1039,10 @@ impl BasicOption for MapList {
     fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
         let value = String::from_value(value)?;
 
-        Ok(value.parse::<Self>().unwrap())
+        let maps = value.parse::<Self>().unwrap();
+
+        Self::with_max(maps.0, Self::MAX_MAPS)
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
     }
 }
 
@@ -688,6 +1245,15 @@ pub struct ServerConfig {
 }
 
 impl ServerConfig {
+    pub const ALL: &[Self] = &[
+        Self::SCRIM_6S_5CP,
+        Self::SCRIM_6S_KOTH,
+        Self::SCRIM_HL_KOTH,
+        Self::HL_STOPWATCH,
+        Self::MATCH_6S_5CP,
+        Self::MATCH_6S_KOTH,
+        Self::MATCH_HL_KOTH,
+    ];
     const HL_STOPWATCH: Self = Self::new("rgl_HL_stopwatch", 55);
     const MATCH_6S_5CP: Self = Self::new("rgl_6s_5cp_match_pro", 109);
     const MATCH_6S_KOTH: Self = Self::new("rgl_6s_koth_pro", 110);
@@ -700,3 +1266,184 @@ impl ServerConfig {
         Self { name, id }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{ActiveEnum, Iterable};
+
+    use super::*;
+
+    #[test]
+    fn game_format_round_trips_through_i16() {
+        for format in GameFormat::iter() {
+            let value = format.to_value();
+
+            assert_eq!(value, format as i16);
+            assert_eq!(GameFormat::try_from_value(&value), Ok(format));
+        }
+
+        assert_eq!(GameFormat::try_from_value(&6), Ok(GameFormat::Sixes));
+        assert_eq!(GameFormat::try_from_value(&9), Ok(GameFormat::Highlander));
+        assert!(GameFormat::try_from_value(&7).is_err());
+    }
+
+    #[test]
+    fn map_server_configs_cover_every_kind_and_format() {
+        use GameFormat::{Highlander, Sixes};
+        use GameKind::{Match, Scrim};
+
+        for (map, kind, format, expected) in [
+            (
+                "cp_process_f12",
+                Scrim,
+                Sixes,
+                Some(ServerConfig::SCRIM_6S_5CP),
+            ),
+            (
+                "koth_product_final",
+                Scrim,
+                Sixes,
+                Some(ServerConfig::SCRIM_6S_KOTH),
+            ),
+            ("pl_upward_f12", Scrim, Sixes, None),
+            (
+                "cp_process_f12",
+                Match,
+                Sixes,
+                Some(ServerConfig::MATCH_6S_5CP),
+            ),
+            (
+                "koth_product_final",
+                Match,
+                Sixes,
+                Some(ServerConfig::MATCH_6S_KOTH),
+            ),
+            ("pl_upward_f12", Match, Sixes, None),
+            (
+                "pl_upward_f12",
+                Scrim,
+                Highlander,
+                Some(ServerConfig::HL_STOPWATCH),
+            ),
+            (
+                "cp_steel_f12",
+                Scrim,
+                Highlander,
+                Some(ServerConfig::HL_STOPWATCH),
+            ),
+            (
+                "koth_ashville_final",
+                Scrim,
+                Highlander,
+                Some(ServerConfig::SCRIM_HL_KOTH),
+            ),
+            (
+                "pl_upward_f12",
+                Match,
+                Highlander,
+                Some(ServerConfig::HL_STOPWATCH),
+            ),
+            (
+                "cp_steel_f12",
+                Match,
+                Highlander,
+                Some(ServerConfig::HL_STOPWATCH),
+            ),
+            (
+                "koth_ashville_final",
+                Match,
+                Highlander,
+                Some(ServerConfig::MATCH_HL_KOTH),
+            ),
+            ("ultiduo_baloo_v2", Match, Highlander, None),
+        ] {
+            assert_eq!(
+                Map::new(map).server_config(kind, format),
+                expected,
+                "{map} {kind:?} {format}"
+            );
+        }
+    }
+
+    #[test]
+    fn reservation_links_on_any_serveme_host_are_accepted() {
+        for link in [
+            "https://na.serveme.tf/reservations/123456",
+            "https://eu.serveme.tf/reservations/123456",
+            "http://serveme.tf/reservations/123456",
+            "sea.serveme.tf/reservations/123456",
+        ] {
+            assert_eq!(
+                link.parse::<JoinInfo>().unwrap(),
+                JoinInfo::Reservation(ReservationId(123_456)),
+                "{link}"
+            );
+        }
+
+        assert!(matches!(
+            r#"connect 1.2.3.4:27015; password "pw""#.parse::<JoinInfo>(),
+            Ok(JoinInfo::ConnectInfo(_))
+        ));
+    }
+
+    #[test]
+    fn reservation_url_uses_the_given_host() {
+        assert_eq!(
+            ReservationId(123_456).url("eu.serveme.tf"),
+            "https://eu.serveme.tf/reservations/123456"
+        );
+    }
+
+    #[test]
+    fn serveme_hosts_are_normalized_and_validated() {
+        for (input, expected) in [
+            ("eu.serveme.tf", "eu.serveme.tf"),
+            (" https://NA.serveme.tf/ ", "na.serveme.tf"),
+            ("serveme.tf", "serveme.tf"),
+        ] {
+            assert_eq!(input.parse::<ServemeHost>().unwrap().0, expected, "{input}");
+        }
+
+        for input in [
+            "",
+            "example.com",
+            "eu.serveme.tf/reservations",
+            "evilserveme.tf",
+        ] {
+            assert!(input.parse::<ServemeHost>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn opponent_comes_from_one_option() {
+        let user = UserId::new(1);
+        let recent = OpponentUserId::from(UserId::new(2));
+
+        assert_eq!(OpponentUserId::pick(None, None).unwrap(), None);
+        assert_eq!(
+            OpponentUserId::pick(Some(user), None).unwrap(),
+            Some(user.into())
+        );
+        assert_eq!(
+            OpponentUserId::pick(None, Some(recent)).unwrap(),
+            Some(recent)
+        );
+        assert!(matches!(
+            OpponentUserId::pick(Some(user), Some(recent)),
+            Err(BotError::ConflictingOpponents)
+        ));
+    }
+
+    #[test]
+    fn map_lists_are_capped() {
+        let maps = "cp_process_f12 koth_product_final cp_gullywash_f9"
+            .parse::<MapList>()
+            .unwrap();
+
+        assert!(MapList::with_max(maps.0.clone(), 3).is_ok());
+        assert!(matches!(
+            MapList::with_max(maps.0, 2),
+            Err(BotError::TooManyMaps { count: 3, max: 2 })
+        ));
+    }
+}
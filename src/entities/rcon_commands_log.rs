@@ -0,0 +1,71 @@
+use sea_orm::{QueryOrder, QuerySelect, entity::prelude::*};
+use time::OffsetDateTime;
+
+use super::{RconExecutorId, TeamGuildId};
+
+/// How much of a command's response is kept.
+pub const MAX_RESPONSE_CHARS: usize = 1000;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "rcon_commands_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub guild_id: TeamGuildId,
+    pub game_timestamp: OffsetDateTime,
+    #[sea_orm(column_type = "Text")]
+    pub command: String,
+    #[sea_orm(column_type = "Text")]
+    pub response_truncated: String,
+    pub executed_by: RconExecutorId,
+    pub executed_at: OffsetDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::team_guild::Entity",
+        from = "Column::GuildId",
+        to = "super::team_guild::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    TeamGuild,
+    #[sea_orm(
+        belongs_to = "super::game::Entity",
+        from = "(Column::GuildId, Column::GameTimestamp)",
+        to = "(super::game::Column::GuildId, super::game::Column::Timestamp)",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Game,
+}
+
+impl Related<super::team_guild::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TeamGuild.def()
+    }
+}
+
+impl Related<super::game::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Game.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Entity {
+    /// The most recent commands run on a game's server, newest first.
+    pub fn find_recent(
+        guild_id: TeamGuildId,
+        game_timestamp: OffsetDateTime,
+        limit: u64,
+    ) -> Select<Self> {
+        Self::find()
+            .filter(Column::GuildId.eq(guild_id))
+            .filter(Column::GameTimestamp.eq(game_timestamp))
+            .order_by_desc(Column::ExecutedAt)
+            .limit(limit)
+    }
+}
@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+use super::TeamGuildId;
+
+/// The placeholder in a macro's command that is replaced with the server's
+/// first map when the macro is run.
+pub const MAP_PLACEHOLDER: &str = "{map}";
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "rcon_macro")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub guild_id: TeamGuildId,
+    #[sea_orm(primary_key, auto_increment = false, column_type = "Text")]
+    pub name: String,
+    #[sea_orm(column_type = "Text")]
+    pub command: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::team_guild::Entity",
+        from = "Column::GuildId",
+        to = "super::team_guild::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    TeamGuild,
+}
+
+impl Related<super::team_guild::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TeamGuild.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+
+use super::{GameFormat, MapList, TeamGuildId};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "scrim_template")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub guild_id: TeamGuildId,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub name: String,
+    pub game_format: GameFormat,
+    pub maps: MapList,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::team_guild::Entity",
+        from = "Column::GuildId",
+        to = "super::team_guild::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    TeamGuild,
+}
+
+impl Related<super::team_guild::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TeamGuild.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
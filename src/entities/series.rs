@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use sea_orm::{QueryOrder, QuerySelect, entity::prelude::*};
+use time::OffsetDateTime;
+
+use super::{
+    TeamGuildId,
+    game::{self, Game},
+};
+use crate::BotResult;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "series")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub guild_id: TeamGuildId,
+    /// How many games the series is played over.
+    pub n: i16,
+    pub created_at: OffsetDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::team_guild::Entity",
+        from = "Column::GuildId",
+        to = "super::team_guild::Column::Id",
+        on_update = "NoAction",
+        on_delete = "NoAction"
+    )]
+    TeamGuild,
+}
+
+impl Related<super::team_guild::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TeamGuild.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Entity {
+    /// Labels like "Series (Game 1 of 3)" for the games that are part of a
+    /// series, keyed by game timestamp.
+    pub async fn labels<D: Sync>(
+        db: &impl ConnectionTrait,
+        games: &[Game<D>],
+    ) -> BotResult<HashMap<OffsetDateTime, String>> {
+        let series_ids = games.iter().filter_map(|g| g.series_id).collect::<Vec<_>>();
+
+        if series_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let lengths = Self::find()
+            .filter(Column::Id.is_in(series_ids.iter().copied()))
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|series| (series.id, series.n))
+            .collect::<HashMap<_, _>>();
+
+        // past games count towards a game's number too, so every game in the
+        // series is fetched, not just the ones being labelled.
+        let members = game::Entity::find()
+            .select_only()
+            .column(game::Column::SeriesId)
+            .column(game::Column::Timestamp)
+            .filter(game::Column::SeriesId.is_in(series_ids))
+            .order_by_asc(game::Column::Timestamp)
+            .into_tuple::<(i32, OffsetDateTime)>()
+            .all(db)
+            .await?;
+
+        let mut numbers = HashMap::<i32, i16>::new();
+
+        Ok(members
+            .into_iter()
+            .filter_map(|(series_id, timestamp)| {
+                let number = numbers.entry(series_id).or_default();
+                *number += 1;
+
+                let n = lengths.get(&series_id)?;
+
+                Some((timestamp, format!("Series (Game {number} of {n})")))
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,18 @@
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+
+use super::LinkedUserId;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "steam_mapping")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: LinkedUserId,
+    pub steam_id: i64,
+    pub linked_at: OffsetDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
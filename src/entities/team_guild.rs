@@ -1,46 +1,65 @@
 use std::{
     collections::{BTreeMap, HashSet},
     convert::identity,
-    iter, mem,
+    iter,
     string::ToString,
+    sync::LazyLock,
 };
 
+use moka::{Expiry, future::Cache};
 use sea_orm::{
     ActiveValue::Set,
-    DatabaseTransaction, IntoActiveModel, QueryOrder, QuerySelect, SelectModel, Selector,
+    DatabaseTransaction, IntoActiveModel, QueryOrder, QuerySelect, QueryTrait, SelectModel,
+    Selector,
     entity::prelude::*,
     sea_query::{Func, SimpleExpr},
 };
 use serenity::{
     all::{
         AutocompleteChoice, CommandInteraction, Context, CreateAutocompleteResponse, CreateEmbed,
-        CreateInteractionResponse, CreateMessage, DiscordJsonError, EditMessage, ErrorResponse,
-        HttpError, Mentionable,
+        CreateInteractionResponse, CreateMessage, DiscordJsonError, EditChannel, EditMessage,
+        ErrorResponse, FormattedTimestamp, FormattedTimestampStyle, HttpError, Mentionable,
     },
-    futures::{StreamExt, TryStreamExt, stream},
+    futures::{StreamExt, TryStreamExt, future, stream},
 };
 use time::{Date, Duration, OffsetDateTime, Time};
+use tracing::{debug, warn};
+use tz::TimeZoneRef;
 
 use super::{
-    GameFormat, MapList, ReservationId, ScheduleChannelId, ScheduleMessageId, ServemeApiKey,
-    TeamGuildId,
-    game::{Game, GameDetails, ScrimOrMatch},
+    GameFormat, GameVoiceChannelId, MapList, OpponentUserId, ReminderChannelId, ReservationId,
+    ScheduleChannelId, ScheduleMessageId, ServemeApiKey, ServemeHost, TeamGuildId, TeamRoleId,
+    Timezone,
+    game::{Game, GameDetails, GameKind, ScrimOrMatch},
 };
 use crate::{
     BotResult,
     autocomplete::{
-        DEFAULT_TIME_CHOICES, TIME_CHOICES, day_aliases, day_choices, split_datetime_query,
-        time_aliases,
+        DEFAULT_TIME_CHOICES, TIME_CHOICES, day_aliases, day_choices, is_date_query,
+        parse_date_query, parse_time_query, split_datetime_query, time_aliases,
     },
     components::RefreshButton,
-    entities::game,
+    entities::{game, rcon_commands_log, rcon_macro, scrim_template, series},
     error::BotError,
     rgl::RglTeamId,
-    serveme::{GetReservationRequest, MapsRequest, ReservationResponse},
-    utils::{OffsetDateTimeEtExt, date_string},
+    serveme::{
+        self, AllMaps, FindServersRequest, GetReservationRequest, MapsRequest, ReservationResponse,
+        ServemeAccount,
+    },
+    utils::{
+        date_string, datetime_string, duration_string, relative_date_string, time_string,
+        tz_offset, warning_embed,
+    },
 };
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, DeriveEntityModel)]
+const DEFAULT_MIN_NOTICE_MINUTES: i32 = 30;
+
+/// Discord rejects autocomplete choice names over 100 characters.
+fn truncate_choice(name: &str) -> String {
+    name.chars().take(100).collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "team_guild")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -51,9 +70,109 @@ pub struct Model {
     pub schedule_message_id: Option<ScheduleMessageId>,
     pub serveme_api_key: Option<ServemeApiKey>,
     pub scrim_division: Option<String>,
+    pub show_countdown: Option<bool>,
+    pub min_notice_minutes: Option<i32>,
+    pub game_voice_channel_id: Option<GameVoiceChannelId>,
+    pub team_role_id: Option<TeamRoleId>,
+    pub reminder_channel_id: Option<ReminderChannelId>,
+    pub timezone: Option<Timezone>,
+    pub practice_config_id: Option<i32>,
+    pub default_maps: Option<MapList>,
+    pub scrim_emoji: Option<String>,
+    pub match_emoji: Option<String>,
+    pub block_overlaps: Option<bool>,
+    pub created_at: OffsetDateTime,
+    pub serveme_host: Option<ServemeHost>,
 }
 
 impl Model {
+    pub fn time_zone(&self) -> TimeZoneRef<'static> {
+        self.timezone
+            .as_ref()
+            .map_or(tzdb::time_zone::america::NEW_YORK, Timezone::time_zone)
+    }
+
+    pub fn to_local(&self, datetime: OffsetDateTime) -> OffsetDateTime {
+        datetime.to_offset(tz_offset(self.time_zone(), datetime))
+    }
+
+    pub fn now_local(&self) -> OffsetDateTime {
+        self.to_local(OffsetDateTime::now_utc())
+    }
+
+    /// The instant at which it is `time` on `date` in the guild's timezone.
+    pub fn new_local(&self, date: Date, time: Time) -> OffsetDateTime {
+        let datetime = OffsetDateTime::new_utc(date, time);
+
+        datetime.replace_offset(tz_offset(self.time_zone(), datetime))
+    }
+
+    pub fn format_datetime(&self, datetime: OffsetDateTime) -> String {
+        datetime_string(self.to_local(datetime))
+    }
+
+    pub fn format_datetime_relative(&self, datetime: OffsetDateTime) -> String {
+        let local = self.to_local(datetime);
+
+        format!(
+            "{} at {}",
+            relative_date_string(local.date(), self.now_local().date()),
+            time_string(local.time())
+        )
+    }
+
+    pub async fn get_scrim_template(
+        &self,
+        tx: &DatabaseTransaction,
+        name: &str,
+    ) -> BotResult<scrim_template::Model> {
+        scrim_template::Entity::find_by_id((self.id, name.to_owned()))
+            .one(tx)
+            .await?
+            .ok_or(BotError::ScrimTemplateNotFound)
+    }
+
+    pub async fn autocomplete_scrim_templates(
+        &self,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        tx: DatabaseTransaction,
+        query: &str,
+    ) -> BotResult {
+        let query = query.trim().to_lowercase();
+
+        let templates = self
+            .find_related(scrim_template::Entity)
+            .order_by_asc(scrim_template::Column::Name)
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let choices = templates
+            .into_iter()
+            .filter(|t| t.name.to_lowercase().contains(&query))
+            .take(25)
+            .map(|t| {
+                AutocompleteChoice::new(
+                    format!("{} ({}, {})", t.name, t.game_format, t.maps),
+                    t.name,
+                )
+            })
+            .collect();
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn get_game<D: GameDetails>(
         &self,
         tx: &DatabaseTransaction,
@@ -69,23 +188,48 @@ impl Model {
 
     pub fn select_games<D: GameDetails>(
         &self,
+        after: Option<OffsetDateTime>,
         f: impl FnOnce(Select<game::Entity>) -> Select<game::Entity>,
     ) -> Selector<SelectModel<Game<D>>> {
-        f(self
-            .find_related(game::Entity)
-            .filter(
-                game::Column::Timestamp.gt((OffsetDateTime::now_et() - Duration::hours(6))
-                    .min(OffsetDateTime::now_et().replace_time(Time::MIDNIGHT))),
-            )
-            .filter(D::filter_expr())
-            .order_by_asc(game::Column::Timestamp))
-        .into_partial_model()
+        let after = after.unwrap_or_else(|| {
+            let now = self.now_local();
+
+            (now - Duration::hours(6)).min(now.replace_time(Time::MIDNIGHT))
+        });
+
+        f(game::Entity::find_after(self.id, after).filter(D::filter_expr())).into_partial_model()
+    }
+
+    pub fn search_games(
+        &self,
+        opponent: Option<OpponentUserId>,
+        map: Option<&str>,
+        limit: u64,
+    ) -> Selector<SelectModel<Game>> {
+        self.find_related(game::Entity)
+            .apply_if(opponent, |q, opponent| {
+                q.filter(game::Column::OpponentUserId.eq(opponent))
+            })
+            .apply_if(map, |q, map| {
+                let pattern = map
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+
+                q.filter(Expr::cust_with_values(
+                    r#"array_to_string("game"."maps", ' ') ILIKE $1"#,
+                    [format!("%{pattern}%")],
+                ))
+            })
+            .order_by_desc(game::Column::Timestamp)
+            .limit(limit)
+            .into_partial_model()
     }
 
     pub async fn select_closest_active_games<D: GameDetails>(
         &self,
     ) -> BotResult<Selector<SelectModel<Game<D>>>> {
-        let reservations = GetReservationRequest::send_many(self.serveme_api_key()?).await?;
+        let reservations = GetReservationRequest::send_many(&self.serveme_account()?).await?;
 
         let ready_reservation_ids = reservations
             .iter()
@@ -96,7 +240,7 @@ impl Model {
             .find_related(game::Entity)
             .filter(D::filter_expr())
             .filter(game::Column::ReservationId.is_in(ready_reservation_ids))
-            .order_by_desc(game::Column::Timestamp.lt(OffsetDateTime::now_et()))
+            .order_by_desc(game::Column::Timestamp.lt(OffsetDateTime::now_utc()))
             .order_by_asc(SimpleExpr::from(Func::greatest([
                 game::Column::Timestamp
                     .into_expr()
@@ -109,6 +253,36 @@ impl Model {
             .into_partial_model())
     }
 
+    /// The game whose reservation is currently ready and whose start time is
+    /// closest to now.
+    pub async fn closest_active_game<D: GameDetails>(
+        &self,
+        tx: &DatabaseTransaction,
+    ) -> BotResult<Game<D>> {
+        self.select_closest_active_games::<D>()
+            .await?
+            .one(tx)
+            .await?
+            .ok_or(BotError::NoActiveGames)
+    }
+
+    /// How many games the guild has on record, including past ones.
+    pub async fn game_count(&self, tx: &DatabaseTransaction) -> BotResult<u64> {
+        Ok(game::Entity::find()
+            .filter(game::Column::GuildId.eq(self.id))
+            .count(tx)
+            .await?)
+    }
+
+    /// How many of the guild's games haven't started yet.
+    pub async fn upcoming_game_count(&self, tx: &DatabaseTransaction) -> BotResult<u64> {
+        Ok(game::Entity::find()
+            .filter(game::Column::GuildId.eq(self.id))
+            .filter(game::Column::Timestamp.gt(OffsetDateTime::now_utc()))
+            .count(tx)
+            .await?)
+    }
+
     pub async fn ensure_time_open(
         &self,
         tx: &DatabaseTransaction,
@@ -125,16 +299,225 @@ impl Model {
             .ok_or(BotError::TimeSlotTaken)
     }
 
-    pub fn serveme_api_key(&self) -> BotResult<&ServemeApiKey> {
-        self.serveme_api_key
-            .as_ref()
-            .ok_or(BotError::NoServemeApiKey)
+    /// Check whether a hosted `kind` game at `date_time` would need a server
+    /// at the same time as an existing hosted game, ignoring the game at
+    /// `exclude`. Depending on the guild's config, an overlap is either
+    /// rejected or returned as a warning to show with the response.
+    pub async fn check_overlap(
+        &self,
+        tx: &DatabaseTransaction,
+        date_time: OffsetDateTime,
+        kind: GameKind,
+        exclude: Option<OffsetDateTime>,
+    ) -> BotResult<Option<CreateEmbed>> {
+        let (starts_at, ends_at) = kind.reservation_window(date_time);
+
+        // a game's window starts at most 15 minutes before it and ends at most
+        // the longest game duration plus 15 minutes after it.
+        let candidates = game::Entity::find()
+            .filter(game::Column::GuildId.eq(self.id))
+            .filter(game::Column::ReservationId.is_not_null())
+            .filter(game::Column::Timestamp.between(
+                starts_at - GameKind::Match.duration() - Duration::minutes(15),
+                ends_at + Duration::minutes(15),
+            ))
+            .apply_if(exclude, |q, exclude| {
+                q.filter(game::Column::Timestamp.ne(exclude))
+            })
+            .order_by_asc(game::Column::Timestamp)
+            .into_partial_model::<Game>()
+            .all(tx)
+            .await?;
+
+        let Some(game) = candidates
+            .into_iter()
+            .find(|game| windows_overlap(game.start_end_times(), (starts_at, ends_at)))
+        else {
+            return Ok(None);
+        };
+
+        let timestamp = game.timestamp.unix_timestamp();
+
+        if self.block_overlaps() {
+            Err(BotError::OverlappingGame { timestamp })
+        } else {
+            Ok(Some(warning_embed(format!(
+                "This game's reservation overlaps the hosted game at <t:{timestamp}:f>, so they \
+                 may compete for a server."
+            ))))
+        }
+    }
+
+    pub fn validate_schedule_time(&self, date_time: OffsetDateTime) -> BotResult {
+        let min_minutes = self.min_notice_minutes();
+
+        if date_time > OffsetDateTime::now_utc() + Duration::minutes(min_minutes.into()) {
+            Ok(())
+        } else {
+            Err(BotError::TooSoonToSchedule { min_minutes })
+        }
+    }
+
+    /// The serveme.tf host the guild's API key belongs to.
+    pub fn serveme_host(&self) -> &str {
+        match &self.serveme_host {
+            Some(host) => &host.0,
+            None => serveme::default_host(),
+        }
+    }
+
+    pub fn serveme_account(&self) -> BotResult<ServemeAccount> {
+        self.try_serveme_account().ok_or(BotError::NoServemeApiKey)
+    }
+
+    /// Like [`Self::serveme_account`], for when serveme.tf is optional.
+    pub fn try_serveme_account(&self) -> Option<ServemeAccount> {
+        self.serveme_api_key.clone().map(|api_key| ServemeAccount {
+            host: self.serveme_host().to_owned(),
+            api_key,
+        })
     }
 
     pub fn rgl_team_id(&self) -> BotResult<RglTeamId> {
         self.rgl_team_id.ok_or(BotError::NoRglTeam)
     }
 
+    pub fn show_countdown(&self) -> bool {
+        self.show_countdown.unwrap_or(true)
+    }
+
+    pub fn block_overlaps(&self) -> bool {
+        self.block_overlaps.unwrap_or(false)
+    }
+
+    pub fn kind_emoji(&self, kind: GameKind) -> &str {
+        match kind {
+            GameKind::Scrim => self.scrim_emoji.as_deref(),
+            GameKind::Match => self.match_emoji.as_deref(),
+        }
+        .unwrap_or_else(|| kind.emoji())
+    }
+
+    pub fn min_notice_minutes(&self) -> i32 {
+        self.min_notice_minutes
+            .unwrap_or(DEFAULT_MIN_NOTICE_MINUTES)
+    }
+
+    pub async fn get_rcon_macro(
+        &self,
+        tx: &DatabaseTransaction,
+        name: &str,
+    ) -> BotResult<rcon_macro::Model> {
+        rcon_macro::Entity::find_by_id((self.id, name.to_owned()))
+            .one(tx)
+            .await?
+            .ok_or(BotError::RconMacroNotFound)
+    }
+
+    pub async fn autocomplete_rcon_macros(
+        &self,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        tx: DatabaseTransaction,
+        query: &str,
+    ) -> BotResult {
+        let query = query.trim().to_lowercase();
+
+        let macros = self
+            .find_related(rcon_macro::Entity)
+            .order_by_asc(rcon_macro::Column::Name)
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let choices = macros
+            .into_iter()
+            .filter(|m| m.name.to_lowercase().contains(&query))
+            .take(25)
+            .map(|m| {
+                AutocompleteChoice::new(
+                    truncate_choice(&format!("{}: {}", m.name, m.command)),
+                    m.name,
+                )
+            })
+            .collect();
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    /// Suggest the guild's macros, followed by its recently run commands.
+    pub async fn autocomplete_rcon_commands(
+        &self,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        tx: DatabaseTransaction,
+        query: &str,
+    ) -> BotResult {
+        let query = query.trim().to_lowercase();
+
+        let macros = self
+            .find_related(rcon_macro::Entity)
+            .order_by_asc(rcon_macro::Column::Name)
+            .all(&tx)
+            .await?;
+
+        let recent = rcon_commands_log::Entity::find()
+            .filter(rcon_commands_log::Column::GuildId.eq(self.id))
+            .order_by_desc(rcon_commands_log::Column::ExecutedAt)
+            .limit(100)
+            .select_only()
+            .column(rcon_commands_log::Column::Command)
+            .into_tuple::<String>()
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let mut seen = HashSet::new();
+
+        let macro_choices = macros
+            .into_iter()
+            .filter(|m| {
+                m.name.to_lowercase().contains(&query) || m.command.to_lowercase().contains(&query)
+            })
+            .map(|m| (format!("⭐ {}: {}", m.name, m.command), m.command));
+
+        let recent_choices = recent
+            .into_iter()
+            .filter(|command| command.to_lowercase().contains(&query))
+            .map(|command| (command.clone(), command));
+
+        // values can't be truncated like names, so long commands are left out.
+        let choices = macro_choices
+            .chain(recent_choices)
+            .filter(|(_, command)| command.len() <= 100 && seen.insert(command.clone()))
+            .take(25)
+            .map(|(name, command)| AutocompleteChoice::new(truncate_choice(&name), command))
+            .collect();
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
     pub async fn autocomplete_times(
         &self,
         ctx: &Context,
@@ -144,20 +527,27 @@ impl Model {
     ) -> BotResult {
         let (_, day_query, time_query) = split_datetime_query(query);
 
-        let dates = day_choices()
-            .filter_map(|(date, names)| {
-                names
-                    .iter()
-                    .any(|n| n.starts_with(&day_query))
-                    .then_some(date)
-            })
-            .collect::<Vec<_>>();
+        let now = self.now_local();
+        let today = now.date();
+
+        let dates = if is_date_query(&day_query) {
+            parse_date_query(&day_query, today)
+                .into_iter()
+                .collect::<Vec<_>>()
+        } else {
+            day_choices(today)
+                .filter_map(|(date, names)| {
+                    names
+                        .iter()
+                        .any(|n| n.starts_with(&day_query))
+                        .then_some(date)
+                })
+                .collect::<Vec<_>>()
+        };
 
         let taken_datetimes = self
             .find_related(game::Entity)
-            .filter(
-                game::Column::Timestamp.gt(OffsetDateTime::now_et().replace_time(Time::MIDNIGHT)),
-            )
+            .filter(game::Column::Timestamp.gt(now.replace_time(Time::MIDNIGHT)))
             .select_only()
             .column(game::Column::Timestamp)
             .into_tuple::<OffsetDateTime>()
@@ -166,29 +556,37 @@ impl Model {
             .into_iter()
             .collect::<HashSet<_>>();
 
-        let min_timestamp = OffsetDateTime::now_et() - Duration::minutes(30);
+        let min_timestamp = now - Duration::minutes(30);
 
-        let datetimes = match dates.as_slice() {
-            [] => {
+        let datetimes = match (parse_time_query(&time_query), dates.as_slice()) {
+            (Some(time), dates) => dates
+                .iter()
+                .map(|date| self.new_local(*date, time))
+                .filter(|datetime| {
+                    !taken_datetimes.contains(datetime) && datetime >= &min_timestamp
+                })
+                .take(25)
+                .collect::<Vec<_>>(),
+            (None, []) => {
                 vec![]
             }
-            [date] => TIME_CHOICES
+            (None, [date]) => TIME_CHOICES
                 .iter()
                 .filter(|(_, names)| names.iter().any(|n| n.starts_with(&time_query)))
-                .map(|(time, _)| OffsetDateTime::new_et(*date, *time))
+                .map(|(time, _)| self.new_local(*date, *time))
                 .filter(|datetime| {
                     !taken_datetimes.contains(datetime) && datetime >= &min_timestamp
                 })
                 .take(25)
                 .collect::<Vec<_>>(),
-            dates => {
+            (None, dates) => {
                 if time_query.is_empty() {
                     dates
                         .iter()
                         .flat_map(|date| {
                             DEFAULT_TIME_CHOICES
                                 .into_iter()
-                                .map(|time| OffsetDateTime::new_et(*date, time))
+                                .map(|time| self.new_local(*date, time))
                         })
                         .filter(|datetime| {
                             !taken_datetimes.contains(datetime) && datetime >= &min_timestamp
@@ -204,7 +602,7 @@ impl Model {
                                 .filter(|(_, names)| {
                                     names.iter().any(|n| n.starts_with(&time_query))
                                 })
-                                .map(|(time, _)| OffsetDateTime::new_et(*date, *time))
+                                .map(|(time, _)| self.new_local(*date, *time))
                         })
                         .filter(|datetime| {
                             !taken_datetimes.contains(datetime) && datetime >= &min_timestamp
@@ -224,7 +622,7 @@ impl Model {
                             .into_iter()
                             .map(|datetime| {
                                 AutocompleteChoice::new(
-                                    datetime.string_et_relative(),
+                                    self.format_datetime_relative(datetime),
                                     datetime.unix_timestamp(),
                                 )
                             })
@@ -248,18 +646,21 @@ impl Model {
         query: &str,
     ) -> BotResult {
         let (_, day_query, time_query) = split_datetime_query(query);
+        let today = self.now_local().date();
 
         let matches = selector
-            .unwrap_or_else(|| self.select_games::<D>(identity))
+            .unwrap_or_else(|| self.select_games::<D>(None, identity))
             .all(&tx)
             .await?
             .into_iter()
             .filter(|game| {
-                let date_matches = day_aliases(game.timestamp.date_et())
+                let local = self.to_local(game.timestamp);
+
+                let date_matches = day_aliases(local.date(), today)
                     .iter()
                     .any(|n| n.starts_with(&day_query));
 
-                let time_matches = time_aliases(game.timestamp.time_et())
+                let time_matches = time_aliases(local.time())
                     .iter()
                     .any(|n| n.starts_with(&time_query));
 
@@ -283,11 +684,10 @@ impl Model {
                                     .unwrap_or_default();
 
                                 BotResult::Ok(AutocompleteChoice::new(
-                                    format!(
-                                        "{}: {}{vs}",
-                                        m.timestamp.string_et_relative(),
-                                        m.details.name(),
-                                    ),
+                                    truncate_choice(&format!(
+                                        "{}{vs}",
+                                        m.details.title(self, m.timestamp)
+                                    )),
                                     m.timestamp.unix_timestamp(),
                                 ))
                             })
@@ -303,6 +703,75 @@ impl Model {
         Ok(())
     }
 
+    pub async fn autocomplete_opponents(
+        &self,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        tx: DatabaseTransaction,
+        query: &str,
+    ) -> BotResult {
+        static NAMES: LazyLock<Cache<OpponentUserId, String>> = LazyLock::new(|| {
+            Cache::builder()
+                .time_to_live(std::time::Duration::from_hours(1))
+                .build()
+        });
+
+        let opponents = game::Entity::find()
+            .select_only()
+            .column(game::Column::OpponentUserId)
+            .filter(game::Column::GuildId.eq(self.id))
+            .filter(game::Column::OpponentUserId.is_not_null())
+            .group_by(game::Column::OpponentUserId)
+            .order_by_desc(game::Column::Timestamp.max())
+            .limit(25)
+            .into_tuple::<OpponentUserId>()
+            .all(&tx)
+            .await?;
+
+        tx.commit().await?;
+
+        let query = query.to_lowercase();
+
+        // autocomplete has to respond within 3 seconds, so the names are
+        // fetched concurrently rather than one user at a time.
+        let names = future::join_all(opponents.iter().map(|&opponent| async move {
+            match NAMES
+                .try_get_with(opponent, async {
+                    let user = opponent.to_user(ctx).await?;
+
+                    BotResult::Ok(user.global_name.unwrap_or(user.name))
+                })
+                .await
+            {
+                Ok(name) => name,
+                Err(error) => {
+                    warn!(?opponent, ?error, "failed to fetch opponent user");
+                    opponent.to_string()
+                }
+            }
+        }))
+        .await;
+
+        let mut choices = Vec::new();
+
+        for (opponent, name) in opponents.into_iter().zip(names) {
+            if name.to_lowercase().contains(&query) || opponent.to_string().starts_with(&query) {
+                choices.push(AutocompleteChoice::new(name, opponent.to_string()));
+            }
+        }
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn autocomplete_reservations<D: GameDetails>(
         &self,
         ctx: &Context,
@@ -312,8 +781,10 @@ impl Model {
         query: &str,
     ) -> BotResult {
         let (query, day_query, time_query) = split_datetime_query(query);
+        let today = self.now_local().date();
 
-        let reservations = GetReservationRequest::send_many(self.serveme_api_key()?).await?;
+        let reservations =
+            GetReservationRequest::send_many_cached(&self.serveme_account()?).await?;
 
         let reservations = reservations.iter().filter(|r| filter(r)).map(|r| r.id);
 
@@ -339,13 +810,13 @@ impl Model {
             .into_iter()
             .filter(|(reservation, datetimes)| {
                 let date_matches = datetimes.iter().any(|datetime| {
-                    day_aliases(datetime.date_et())
+                    day_aliases(self.to_local(*datetime).date(), today)
                         .iter()
                         .any(|n| n.starts_with(&day_query))
                 });
 
                 let time_matches = datetimes.iter().any(|datetime| {
-                    time_aliases(datetime.time_et())
+                    time_aliases(self.to_local(*datetime).time())
                         .iter()
                         .any(|n| n.starts_with(&time_query))
                 });
@@ -364,7 +835,7 @@ impl Model {
                         data.map(|(reservation, datetimes)| {
                             let datetimes = datetimes
                                 .iter()
-                                .map(OffsetDateTime::string_et_relative)
+                                .map(|datetime| self.format_datetime_relative(*datetime))
                                 .collect::<Vec<_>>()
                                 .join(", ");
 
@@ -395,13 +866,16 @@ impl Model {
 
         let maps = query.parse::<MapList>().unwrap();
 
-        let all_maps = MapsRequest::send(
-            self.serveme_api_key
-                .as_ref()
-                .ok_or(BotError::NoServemeApiKey)?,
-            game_format,
-        )
-        .await?;
+        // official maps can still be offered without serveme.tf.
+        let all_maps = match self.try_serveme_account() {
+            Some(account) => match MapsRequest::send(&account, game_format).await {
+                Err(error) if error.is_serveme_rate_limited() => {
+                    AllMaps::official_only(game_format)
+                }
+                result => result?,
+            },
+            None => AllMaps::official_only(game_format),
+        };
 
         let trailing_sep =
             query.ends_with(',') || query.ends_with('/') || query.ends_with(char::is_whitespace);
@@ -419,16 +893,105 @@ impl Model {
         Ok(())
     }
 
+    pub async fn autocomplete_servers(
+        &self,
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        kind: GameKind,
+        timestamp: Option<OffsetDateTime>,
+        query: &str,
+    ) -> BotResult {
+        let choices = if let Some(timestamp) = timestamp {
+            let (starts_at, ends_at) = kind.reservation_window(timestamp);
+
+            let servers = FindServersRequest { starts_at, ends_at }
+                .send_without_retry(&self.serveme_account()?)
+                .await?;
+
+            let query = query.to_lowercase();
+
+            servers
+                .servers
+                .iter()
+                .filter(|server| server.ip_and_port.to_lowercase().contains(&query))
+                .take(25)
+                .map(|server| AutocompleteChoice::new(server.ip_and_port.clone(), server.id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Autocomplete(
+                    CreateAutocompleteResponse::new().set_choices(choices),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn games_page(
+        &self,
+        ctx: &Context,
+        tx: &DatabaseTransaction,
+        after: Option<OffsetDateTime>,
+    ) -> BotResult<(CreateEmbed, Option<OffsetDateTime>)> {
+        const PAGE_SIZE: usize = 10;
+
+        let mut games = self
+            .select_games::<ScrimOrMatch>(after, |s| s.limit(PAGE_SIZE as u64 + 1))
+            .all(tx)
+            .await?;
+
+        let next = if games.len() > PAGE_SIZE {
+            games.truncate(PAGE_SIZE);
+            games.last().map(|game| game.timestamp)
+        } else {
+            None
+        };
+
+        let mut lines = Vec::with_capacity(games.len());
+
+        for game in &games {
+            let vs = game
+                .details
+                .opponent_string(ctx, self.rgl_team_id)
+                .await?
+                .map(|opponent| format!(" vs. {opponent}"))
+                .unwrap_or_default();
+
+            lines.push(format!("{}{vs}", game.details.title(self, game.timestamp)));
+        }
+
+        let description = if lines.is_empty() {
+            "No more games.".to_owned()
+        } else {
+            lines.join("\n")
+        };
+
+        Ok((
+            CreateEmbed::new()
+                .title("📋 Games")
+                .description(description),
+            next,
+        ))
+    }
+
     async fn schedule_embed(&self, tx: &DatabaseTransaction) -> BotResult<CreateEmbed> {
         let games = self
-            .select_games::<ScrimOrMatch>(|s| s.limit(25))
+            .select_games::<ScrimOrMatch>(None, |s| s.limit(25))
             .all(tx)
             .await?;
 
+        let series_labels = series::Entity::labels(tx, &games).await?;
+
         let mut map = BTreeMap::<Date, Vec<Game>>::new();
 
         for game in games {
-            let date = game.timestamp.date_et();
+            let date = self.to_local(game.timestamp).date();
 
             map.entry(date).or_default().push(game);
         }
@@ -453,7 +1016,12 @@ impl Model {
                             .and_then(async |(game, next_game)| {
                                 let include_connect = !next_game
                                     .is_some_and(|next_game| game.server == next_game.server);
-                                game.schedule_entry(self, include_connect).await
+                                game.schedule_entry(
+                                    self,
+                                    include_connect,
+                                    series_labels.get(&game.timestamp).map(String::as_str),
+                                )
+                                .await
                             })
                             .try_collect::<String>()
                             .await?,
@@ -468,6 +1036,40 @@ impl Model {
         Ok(embed)
     }
 
+    /// Delete a game and refresh the schedule, returning the deleted game.
+    pub async fn delete_game(
+        &mut self,
+        ctx: &Context,
+        tx: &DatabaseTransaction,
+        timestamp: OffsetDateTime,
+    ) -> BotResult<Game> {
+        let game = game::Entity::delete_by_id((self.id, timestamp))
+            .exec_with_returning(tx)
+            .await?
+            .pop()
+            .ok_or(BotError::GameNotFound)?;
+
+        self.refresh_schedule(ctx, tx).await?;
+
+        Game::try_from(game)
+    }
+
+    /// Delete the guild's games that started more than `older_than` ago,
+    /// returning how many were deleted.
+    pub async fn cleanup_old_games(
+        &self,
+        tx: &DatabaseTransaction,
+        older_than: Duration,
+    ) -> BotResult<u64> {
+        let res = game::Entity::delete_many()
+            .filter(game::Column::GuildId.eq(self.id))
+            .filter(game::Column::Timestamp.lt(OffsetDateTime::now_utc() - older_than))
+            .exec(tx)
+            .await?;
+
+        Ok(res.rows_affected)
+    }
+
     pub async fn refresh_schedule(&mut self, ctx: &Context, tx: &DatabaseTransaction) -> BotResult {
         let Some(schedule_channel) = self.schedule_channel_id else {
             return Err(BotError::NoScheduleChannel);
@@ -504,13 +1106,142 @@ impl Model {
             )
             .await?;
 
-        let mut guild = mem::take(self).into_active_model();
+        let mut guild = self.clone().into_active_model();
         guild.schedule_message_id = Set(Some(message.id.into()));
         *self = guild.update(tx).await?;
 
         Ok(())
     }
 
+    /// Show the STV connect info of the closest active game as the game voice
+    /// channel's status, or clear it if there is none. Voice channels have no
+    /// topic, so the status is used instead.
+    pub async fn sync_voice_channel(&self, ctx: &Context, tx: &DatabaseTransaction) -> BotResult {
+        static STATUSES: LazyLock<Cache<TeamGuildId, String>> =
+            LazyLock::new(|| Cache::builder().build());
+
+        let Some(voice_channel) = self.game_voice_channel_id else {
+            return Ok(());
+        };
+
+        let game = self
+            .select_closest_active_games::<ScrimOrMatch>()
+            .await?
+            .one(tx)
+            .await?;
+
+        let status = if let Some(game) = game {
+            let reservation = game.get_reservation(&self.serveme_account()?).await?;
+
+            format!("STV: {}", reservation.stv_connect_info())
+        } else {
+            String::new()
+        };
+
+        if STATUSES.get(&self.id).await.as_ref() != Some(&status) {
+            voice_channel
+                .edit(ctx, EditChannel::new().status(&status))
+                .await?;
+
+            STATUSES.insert(self.id, status).await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_reminders(
+        &self,
+        ctx: &Context,
+        tx: &DatabaseTransaction,
+        minutes: i64,
+    ) -> BotResult {
+        /// Forgets a reminder once its game has started, since the game then
+        /// drops out of the reminder window however long that window is.
+        struct UntilGameStarts;
+
+        impl Expiry<(TeamGuildId, OffsetDateTime), ()> for UntilGameStarts {
+            fn expire_after_create(
+                &self,
+                (_, timestamp): &(TeamGuildId, OffsetDateTime),
+                (): &(),
+                _: std::time::Instant,
+            ) -> Option<std::time::Duration> {
+                Some(
+                    (*timestamp - OffsetDateTime::now_utc() + Duration::minutes(1))
+                        .try_into()
+                        .unwrap_or_default(),
+                )
+            }
+        }
+
+        static REMINDED: LazyLock<Cache<(TeamGuildId, OffsetDateTime), ()>> =
+            LazyLock::new(|| Cache::builder().expire_after(UntilGameStarts).build());
+
+        let Some(role) = self.team_role_id else {
+            return Ok(());
+        };
+
+        let Some(channel) = self
+            .reminder_channel_id
+            .map(|c| c.0)
+            .or_else(|| self.schedule_channel_id.map(|c| c.0))
+        else {
+            debug!(?self.id, "no reminder or schedule channel set, skipping reminders");
+            return Ok(());
+        };
+
+        let now = OffsetDateTime::now_utc();
+
+        let games = self
+            .find_related(game::Entity)
+            .filter(game::Column::Timestamp.between(now, now + Duration::minutes(minutes)))
+            .order_by_asc(game::Column::Timestamp)
+            .into_partial_model::<Game>()
+            .all(tx)
+            .await?;
+
+        for game in games {
+            let key = (self.id, game.timestamp);
+
+            if REMINDED.contains_key(&key) {
+                continue;
+            }
+
+            let embed = match game.embed(self).await {
+                Ok(embed) => embed,
+                Err(error) => {
+                    warn!(?self.id, ?game.timestamp, ?error, "failed to build reminder embed");
+                    continue;
+                }
+            };
+
+            // round up, so a game 14m30s away is announced as 15 minutes away
+            let starts_in = ((game.timestamp - now).whole_seconds() + 59) / 60;
+
+            if let Err(error) = channel
+                .send_message(
+                    ctx,
+                    CreateMessage::new()
+                        .content(format!(
+                            "{} Game starting in {}!",
+                            role.mention(),
+                            duration_string(starts_in)
+                        ))
+                        .embed(embed),
+                )
+                .await
+            {
+                warn!(?self.id, ?game.timestamp, ?error, "failed to send game reminder");
+                continue;
+            }
+
+            REMINDED.insert(key, ()).await;
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
     pub fn config_embed(&self) -> CreateEmbed {
         CreateEmbed::new()
             .title("⚙️ Configuration")
@@ -523,7 +1254,7 @@ impl Model {
                 true,
             )
             .field(
-                "na.serveme.tf API Key",
+                format!("{} API Key", self.serveme_host()),
                 self.serveme_api_key.as_ref().map_or_else(
                     || "Not set".to_owned(),
                     |key| format!("`{}`", "*".repeat(key.0.len())),
@@ -544,12 +1275,101 @@ impl Model {
                     .map_or_else(|| "Not set".to_owned(), |d| format!("`{d}`")),
                 true,
             )
+            .field(
+                "Schedule Countdowns",
+                if self.show_countdown() {
+                    "Enabled"
+                } else {
+                    "Disabled"
+                },
+                true,
+            )
+            .field(
+                "Overlapping Games",
+                if self.block_overlaps() {
+                    "Blocked"
+                } else {
+                    "Warned about"
+                },
+                true,
+            )
+            .field(
+                "Minimum Notice",
+                self.min_notice_minutes.map_or_else(
+                    || format!("{DEFAULT_MIN_NOTICE_MINUTES} minutes (default)"),
+                    |minutes| format!("{minutes} minutes"),
+                ),
+                true,
+            )
             .field(
                 "Schedule Channel",
                 self.schedule_channel_id
                     .map_or_else(|| "Not set".to_owned(), |c| c.mention().to_string()),
                 true,
             )
+            .field(
+                "Game Voice Channel",
+                self.game_voice_channel_id
+                    .map_or_else(|| "Not set".to_owned(), |c| c.mention().to_string()),
+                true,
+            )
+            .field(
+                "Reminder Channel",
+                self.reminder_channel_id
+                    .map_or_else(|| "Not set".to_owned(), |c| c.mention().to_string()),
+                true,
+            )
+            .field(
+                "Team Role",
+                self.team_role_id
+                    .map_or_else(|| "Not set".to_owned(), |r| r.mention().to_string()),
+                true,
+            )
+            .field(
+                "Practice Config",
+                self.practice_config_id
+                    .map_or_else(|| "Not set".to_owned(), |id| format!("`{id}`")),
+                true,
+            )
+            .field(
+                "Default Maps",
+                self.default_maps
+                    .as_ref()
+                    .and_then(|maps| maps.list(true))
+                    .unwrap_or_else(|| "Not set".to_owned()),
+                true,
+            )
+            .field(
+                "Scrim Emoji",
+                self.scrim_emoji
+                    .as_deref()
+                    .unwrap_or(GameKind::Scrim.emoji()),
+                true,
+            )
+            .field(
+                "Match Emoji",
+                self.match_emoji
+                    .as_deref()
+                    .unwrap_or(GameKind::Match.emoji()),
+                true,
+            )
+            .field(
+                "Timezone",
+                self.timezone.as_ref().map_or_else(
+                    || "`America/New_York` (default)".to_owned(),
+                    |tz| format!("`{tz}`"),
+                ),
+                true,
+            )
+            .field(
+                "Bot Added",
+                FormattedTimestamp::new(
+                    self.created_at.into(),
+                    Some(FormattedTimestampStyle::RelativeTime),
+                )
+                .to_string(),
+                true,
+            )
             .field(
                 "Schedule Message",
                 self.schedule_message_id
@@ -567,6 +1387,12 @@ impl Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::game::Entity")]
     Game,
+    #[sea_orm(has_many = "super::scrim_template::Entity")]
+    ScrimTemplate,
+    #[sea_orm(has_many = "super::rcon_macro::Entity")]
+    RconMacro,
+    #[sea_orm(has_many = "super::series::Entity")]
+    Series,
 }
 
 impl Related<super::game::Entity> for Entity {
@@ -575,4 +1401,70 @@ impl Related<super::game::Entity> for Entity {
     }
 }
 
+impl Related<super::scrim_template::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ScrimTemplate.def()
+    }
+}
+
+impl Related<super::rcon_macro::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RconMacro.def()
+    }
+}
+
+impl Related<super::series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Series.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
+
+/// Whether two `(starts_at, ends_at)` windows share any time. Windows that only
+/// touch, with one ending as the other starts, don't overlap.
+fn windows_overlap(
+    (a_starts_at, a_ends_at): (OffsetDateTime, OffsetDateTime),
+    (b_starts_at, b_ends_at): (OffsetDateTime, OffsetDateTime),
+) -> bool {
+    a_starts_at < b_ends_at && b_starts_at < a_ends_at
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn same_reservation_window_overlaps() {
+        let window = GameKind::Scrim.reservation_window(datetime!(2024-10-16 21:00 UTC));
+
+        assert!(windows_overlap(window, window));
+    }
+
+    #[test]
+    fn partially_overlapping_windows_overlap() {
+        let scrim = GameKind::Scrim.reservation_window(datetime!(2024-10-16 21:00 UTC));
+        let later = GameKind::Scrim.reservation_window(datetime!(2024-10-16 22:00 UTC));
+        let match_ = GameKind::Match.reservation_window(datetime!(2024-10-16 20:00 UTC));
+
+        assert!(windows_overlap(scrim, later));
+        assert!(windows_overlap(later, scrim));
+        assert!(windows_overlap(scrim, match_));
+    }
+
+    #[test]
+    fn separate_windows_do_not_overlap() {
+        let scrim = GameKind::Scrim.reservation_window(datetime!(2024-10-16 21:00 UTC));
+        let next_day = GameKind::Scrim.reservation_window(datetime!(2024-10-17 21:00 UTC));
+
+        assert!(!windows_overlap(scrim, next_day));
+        assert!(!windows_overlap(next_day, scrim));
+
+        let touching = (scrim.1, scrim.1 + Duration::hours(2));
+
+        assert!(!windows_overlap(scrim, touching));
+        assert!(!windows_overlap(touching, scrim));
+    }
+}
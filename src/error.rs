@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use serenity::all::ChannelId;
 use thiserror::Error;
 
 use crate::serveme;
@@ -18,10 +19,31 @@ pub enum BotError {
     #[error("Command parsing error: `{0}`")]
     CommandParse(#[from] serenity_commands::Error),
 
+    #[error("Too many serveme.tf requests were made recently. Try again in a moment.")]
+    ServemeRateLimited,
+
+    #[error(
+        "serveme.tf is busy right now. Try again {}.",
+        retry_after.map_or_else(|| "later".to_owned(), |secs| format!("in {secs} seconds"))
+    )]
+    RateLimited { retry_after: Option<u64> },
+
     #[error("RCON error: `{0}`")]
     Rcon(#[from] rcon::Error),
 
-    #[error("Serveme error: `{0}`")]
+    #[error("The server isn't ready yet. Check `/game show` to see its status.")]
+    ServerNotReady,
+
+    #[error("Invalid whitelist ID. Use a whitelist.tf ID, e.g. `12345`.")]
+    InvalidWhitelistId,
+
+    #[error("RCON command timed out; the server may still be starting up.")]
+    RconTimeout,
+
+    #[error("This RCON output has expired. Run the command again.")]
+    RconOutputExpired,
+
+    #[error("serveme.tf error: {0}")]
     Serveme(#[from] serveme::ServemeError),
 
     #[error(transparent)]
@@ -30,52 +52,152 @@ pub enum BotError {
     #[error("No guild associated with interaction.")]
     NoGuild,
 
+    #[error("This bot isn't available in this server.")]
+    GuildNotAllowed,
+
     #[error("Invalid interaction target.")]
     InvalidInteractionTarget,
 
     #[error("Invalid component interaction")]
     InvalidComponentInteraction,
 
+    #[error("The game was changed by someone else in the meantime. Please try again.")]
+    ConcurrentModification,
+
     #[error("Invalid game details.")]
     InvalidGameDetails,
 
-    #[error("No na.serveme.tf servers found.")]
+    #[error("No serveme.tf servers found.")]
     NoServemeServers,
 
-    #[error("invalid IP/port from na.servemetf.")]
+    #[error("The selected serveme.tf server is not available at that time.")]
+    ServemeServerUnavailable,
+
+    #[error("The reservation would start in the past. Pick a later time.")]
+    ReservationInPast,
+
+    #[error("The reservation is longer than serveme.tf allows for this account.")]
+    ReservationTooLong,
+
+    #[error(
+        "You already have a serveme.tf reservation at that time. End or reuse it with \
+         `reservation_id`."
+    )]
+    ReservationOverlap,
+
+    #[error("invalid IP/port from serveme.tf.")]
     InvalidServemeIpPort,
 
-    #[error("Invalid connect info.")]
+    #[error(r#"Invalid connect info. Expected `connect <ip>:<port>; password "<password>"`."#)]
     InvalidConnectInfo,
 
+    #[error("Invalid timezone. Use an IANA timezone name, e.g. `America/New_York`.")]
+    InvalidTimezone,
+
+    #[error("Invalid serveme.tf host. Use the host only, e.g. `eu.serveme.tf`.")]
+    InvalidServemeHost,
+
     #[error("Invalid reservation ID.")]
     InvalidReservationId,
 
+    #[error("Too many maps ({count}); at most {max} can be played.")]
+    TooManyMaps { count: usize, max: usize },
+
+    #[error("Invalid scrim result.")]
+    InvalidScrimResult,
+
+    #[error("A series must be a best of 1, 3 or 5.")]
+    InvalidSeriesLength,
+
+    #[error("Games can only be pruned once they are at least a day old.")]
+    InvalidPruneDays,
+
+    #[error("The minimum notice can't be negative.")]
+    NegativeMinNotice,
+
+    #[error("Invalid user. Enter a user ID or mention.")]
+    InvalidUserId,
+
+    #[error("Pick either an opponent or a recent opponent, not both.")]
+    ConflictingOpponents,
+
+    #[error(
+        "Invalid Steam ID. Use a SteamID64, a `STEAM_0:…` or `[U:1:…]` ID, or a Steam community profile URL."
+    )]
+    InvalidSteamId,
+
     #[error("Invalid game server (reservation ID/connect info).")]
     InvalidGameServer,
 
     #[error("Time slot already taken.")]
     TimeSlotTaken,
 
+    #[error("The bot needs the {required} permissions in <#{channel_id}>.")]
+    InsufficientPermissions {
+        channel_id: ChannelId,
+        required: &'static str,
+    },
+
+    #[error("This game's reservation would overlap the hosted game at <t:{timestamp}:f>.")]
+    OverlappingGame { timestamp: i64 },
+
+    #[error("Games must be hosted at least {min_minutes} minutes in advance.")]
+    TooSoonToSchedule { min_minutes: i32 },
+
+    #[error("Provide an opponent or a map to search for.")]
+    NoSearchFilter,
+
     #[error("Game not found.")]
     GameNotFound,
 
-    #[error("No active games found.")]
+    #[error("Scrim template not found.")]
+    ScrimTemplateNotFound,
+
+    #[error("RCON macro not found.")]
+    RconMacroNotFound,
+
+    #[error(
+        "No active games found. Check `/game list` to see upcoming games, or pick a game explicitly."
+    )]
     NoActiveGames,
 
+    #[error("You need the Manage Server permission to do that.")]
+    MissingManageGuild,
+
     #[error("Game not hosted.")]
     GameNotHosted,
 
+    #[error("Game already hosted. Use `/scrim unhost` to give up its server first.")]
+    GameAlreadyHosted,
+
+    #[error("A dry run can only preview new reservations, not existing ones.")]
+    DryRunWithReservation,
+
+    #[error("Plugin and demos.tf options can only be set on new reservations, not existing ones.")]
+    ReservationOptionsWithReservation,
+
+    #[error("Match already has a backup reservation.")]
+    BackupReservationExists,
+
     #[error("No scrims without opponent found.")]
     NoScrimsWithoutOpponent,
 
     #[error("RGL.gg profile not found.")]
     RglProfileNotFound,
 
+    #[error("No Steam account is linked to your Discord account.")]
+    NoSteamMapping,
+
+    #[error("RGL.gg match not found.")]
+    RglMatchNotFound,
+
+    #[error("RGL.gg is unavailable right now. Try again later.")]
+    RglUnavailable,
+
     #[error("Team not in match.")]
     TeamNotInMatch,
 
-    #[error("na.serveme.tf API key not set. Set one with `/config set serveme`.")]
+    #[error("serveme.tf API key not set. Set one with `/config set serveme`.")]
     NoServemeApiKey,
 
     #[error(
@@ -95,8 +217,49 @@ pub enum BotError {
     NoDivision,
 }
 
+impl BotError {
+    pub fn is_serveme_rate_limited(&self) -> bool {
+        match self {
+            Self::ServemeRateLimited | Self::RateLimited { .. } => true,
+            Self::Arc(error) => error.is_serveme_rate_limited(),
+            _ => false,
+        }
+    }
+}
+
 impl From<serenity::Error> for BotError {
     fn from(err: serenity::Error) -> Self {
         Self::Serenity(Box::new(err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use moka::future::Cache;
+
+    use super::*;
+    use crate::BotResult;
+
+    async fn cached(cache: &Cache<u32, u32>, error: BotError) -> BotResult<u32> {
+        Ok(cache.try_get_with(0, async { Err(error) }).await?)
+    }
+
+    #[tokio::test]
+    async fn cache_errors_propagate_as_arc() {
+        let error = cached(&Cache::new(1), BotError::NoRglTeam)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(&error, BotError::Arc(inner) if matches!(**inner, BotError::NoRglTeam)));
+        assert_eq!(error.to_string(), BotError::NoRglTeam.to_string());
+    }
+
+    #[tokio::test]
+    async fn cache_errors_keep_rate_limit_detection() {
+        let error = cached(&Cache::new(1), BotError::ServemeRateLimited)
+            .await
+            .unwrap_err();
+
+        assert!(error.is_serveme_rate_limited());
+    }
+}
@@ -1,17 +1,29 @@
 mod autocomplete;
+mod calendar;
 mod commands;
 mod components;
 mod config;
+mod demostf;
 mod entities;
 mod error;
+mod logstf;
 mod rgl;
 mod serveme;
+mod tasks;
 mod utils;
-
-use std::sync::{Arc, LazyLock};
+#[cfg(feature = "web")]
+mod web;
+
+use std::{
+    sync::{
+        Arc, LazyLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Instant,
+};
 
 use commands::AllCommandsAutocomplete;
-use components::AllComponents;
+use components::{AllComponents, AllModals};
 use entities::team_guild;
 use migration::{Migrator, MigratorTrait};
 use sea_orm::{
@@ -22,17 +34,25 @@ use serenity::all::{
     Command, Context, EventHandler, GatewayIntents, GuildId, Interaction, Ready, async_trait,
 };
 use serenity_commands::{AutocompleteCommands, Commands};
-use tracing::{error, info, instrument};
+use time::OffsetDateTime;
+use tracing::{error, info, instrument, warn};
 use utils::handle_error;
 
-pub use self::config::Config;
+pub use self::config::{Config, ConfigError};
 use self::{commands::AllCommands, error::BotError};
 
 type BotResult<T = ()> = Result<T, BotError>;
 
 pub async fn run(config: Config) -> BotResult {
+    LazyLock::force(&STARTED_AT);
+
     info!("connecting to database...");
 
+    serveme::configure(
+        config.serveme_host.clone(),
+        config.serveme_requests_per_minute,
+    );
+
     let db = Database::connect(&config.database_url).await?;
 
     info!("running migrations...");
@@ -43,6 +63,13 @@ pub async fn run(config: Config) -> BotResult {
         db,
     };
 
+    #[cfg(feature = "web")]
+    if let Some(addr) = bot.config.web_bind_addr {
+        info!("starting web server...");
+
+        tokio::spawn(web::run(bot.clone(), addr));
+    }
+
     info!("building client...");
 
     let mut client = serenity::Client::builder(
@@ -61,6 +88,8 @@ pub async fn run(config: Config) -> BotResult {
 
 static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
 
+static STARTED_AT: LazyLock<Instant> = LazyLock::new(Instant::now);
+
 #[derive(Debug, Clone)]
 pub struct Bot {
     config: Arc<Config>,
@@ -93,6 +122,7 @@ impl Bot {
         } else {
             team_guild::ActiveModel {
                 id: Set(guild_id.into()),
+                created_at: Set(OffsetDateTime::now_utc()),
                 ..Default::default()
             }
             .insert(&tx)
@@ -101,30 +131,68 @@ impl Bot {
 
         Ok((guild, tx))
     }
+
+    /// Reject interactions from guilds outside of the configured allowlist.
+    /// Interactions outside of guilds, e.g. in DMs, are always allowed.
+    fn check_guild_allowed(&self, guild_id: Option<GuildId>) -> BotResult {
+        match (&self.config.allowed_guilds, guild_id) {
+            (Some(allowed_guilds), Some(guild_id)) if !allowed_guilds.contains(&guild_id) => {
+                Err(BotError::GuildNotAllowed)
+            }
+            _ => Ok(()),
+        }
+    }
 }
 
 #[async_trait]
 impl EventHandler for Bot {
     #[instrument(skip(self, ctx))]
-    async fn ready(&self, ctx: Context, _: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
+        static TASKS_STARTED: AtomicBool = AtomicBool::new(false);
+
+        info!(version = env!("CARGO_PKG_VERSION"), "bot ready");
+
+        if let Some(allowed_guilds) = &self.config.allowed_guilds {
+            let disallowed_guilds = ready
+                .guilds
+                .iter()
+                .map(|guild| guild.id)
+                .filter(|id| !allowed_guilds.contains(id))
+                .collect::<Vec<_>>();
+
+            if !disallowed_guilds.is_empty() {
+                warn!(
+                    ?disallowed_guilds,
+                    "bot is in guilds that aren't allowed; their interactions will be rejected"
+                );
+            }
+        }
+
+        if !TASKS_STARTED.swap(true, Ordering::SeqCst) {
+            info!("starting background tasks");
+
+            tokio::spawn(tasks::run(self.clone(), ctx.clone()));
+            tokio::spawn(tasks::run_cleanup(self.clone()));
+        }
+
         let commands = AllCommands::create_commands();
 
         if let Some(guilds) = &self.config.guilds {
             info!(?self.config.guilds, "registering guild commands");
 
+            let (guild_commands, global_commands) =
+                commands.split_at(commands.len() - AllCommands::GLOBAL_COMMANDS);
+
             for guild in guilds {
-                match guild
-                    .set_commands(&ctx.http, commands[..commands.len() - 1].to_vec())
-                    .await
-                {
+                match guild.set_commands(&ctx.http, guild_commands.to_vec()).await {
                     Ok(commands) => info!(?guild, ?commands, "registered guild commands"),
                     Err(error) => error!(?guild, ?error, "failed to register guild commands"),
                 }
             }
 
-            match Command::create_global_command(ctx, commands.last().unwrap().clone()).await {
-                Ok(command) => info!(?command, "registered global user command"),
-                Err(error) => error!(?error, "failed to register global user command"),
+            match Command::set_global_commands(&ctx.http, global_commands.to_vec()).await {
+                Ok(commands) => info!(?commands, "registered global commands"),
+                Err(error) => error!(?error, "failed to register global commands"),
             }
         } else {
             info!("no guilds configured, registering global commands");
@@ -140,6 +208,12 @@ impl EventHandler for Bot {
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
         match interaction {
             Interaction::Command(interaction) => {
+                handle_error!(
+                    ctx,
+                    interaction,
+                    self.check_guild_allowed(interaction.guild_id)
+                );
+
                 let command = handle_error!(
                     ctx,
                     interaction,
@@ -153,6 +227,12 @@ impl EventHandler for Bot {
                 );
             }
             Interaction::Autocomplete(interaction) => {
+                // autocomplete can't show an error, so it's just left
+                // unanswered.
+                if self.check_guild_allowed(interaction.guild_id).is_err() {
+                    return;
+                }
+
                 let command = handle_error!(
                     ctx,
                     interaction,
@@ -167,6 +247,12 @@ impl EventHandler for Bot {
                 );
             }
             Interaction::Component(interaction) => {
+                handle_error!(
+                    ctx,
+                    interaction,
+                    self.check_guild_allowed(interaction.guild_id)
+                );
+
                 let command = handle_error!(
                     ctx,
                     interaction,
@@ -179,6 +265,21 @@ impl EventHandler for Bot {
                     command.run(self, &ctx, &interaction).await
                 );
             }
+            Interaction::Modal(interaction) => {
+                handle_error!(
+                    ctx,
+                    interaction,
+                    self.check_guild_allowed(interaction.guild_id)
+                );
+
+                let modal = handle_error!(
+                    ctx,
+                    interaction,
+                    AllModals::from_modal_data(&interaction.data)
+                );
+
+                handle_error!(ctx, interaction, modal.run(self, &ctx, &interaction).await);
+            }
             _ => {
                 error!(?interaction, "unsupported interaction type");
             }
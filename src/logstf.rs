@@ -0,0 +1,59 @@
+use std::sync::{Arc, LazyLock};
+
+use moka::future::Cache;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::{BotResult, HTTP_CLIENT, entities::ReservationId, serveme::ReservationResponse};
+
+#[derive(Debug, Clone, Deserialize)]
+struct LogsResponse {
+    logs: Vec<Log>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Log {
+    pub id: u64,
+    pub title: String,
+    pub map: String,
+    #[serde(with = "time::serde::timestamp")]
+    pub date: OffsetDateTime,
+}
+
+impl Log {
+    /// Find the logs uploaded by serveme.tf for a reservation.
+    pub async fn for_reservation(reservation: &ReservationResponse) -> BotResult<Arc<Vec<Self>>> {
+        static CACHE: LazyLock<Cache<ReservationId, Arc<Vec<Log>>>> = LazyLock::new(|| {
+            Cache::builder()
+                .time_to_live(std::time::Duration::from_mins(1))
+                .build()
+        });
+
+        Ok(CACHE
+            .try_get_with(reservation.id, async {
+                let response = HTTP_CLIENT
+                    .get("https://logs.tf/api/v1/log")
+                    .query(&[("title", format!("serveme.tf #{}", reservation.id))])
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<LogsResponse>()
+                    .await?;
+
+                Ok(Arc::new(
+                    response
+                        .logs
+                        .into_iter()
+                        .filter(|log| {
+                            (reservation.starts_at..=reservation.ends_at).contains(&log.date)
+                        })
+                        .collect(),
+                ))
+            })
+            .await?)
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://logs.tf/{}", self.id)
+    }
+}
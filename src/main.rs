@@ -1,6 +1,6 @@
 use std::env;
 
-use scheduletf::Config;
+use scheduletf::{Config, ConfigError};
 use tracing_subscriber::EnvFilter;
 
 #[tokio::main]
@@ -9,7 +9,13 @@ async fn main() -> color_eyre::Result<()> {
 
     _ = dotenvy::dotenv();
 
-    let config = Config::from_env()?;
+    let mut args = env::args().skip(1);
+
+    let config = match (args.next().as_deref(), args.next()) {
+        (Some("--config"), Some(path)) => Config::from_file(path)?,
+        (Some("--config"), None) => return Err(ConfigError::MissingPath.into()),
+        _ => Config::from_env()?,
+    };
 
     let fmt = tracing_subscriber::fmt().with_env_filter(
         env::var(EnvFilter::DEFAULT_ENV)
@@ -1,27 +1,37 @@
 use std::{
+    cmp::Ordering,
     fmt::{self, Display, Formatter},
     hash::Hash,
     result::Result,
+    str::FromStr,
     sync::{Arc, LazyLock},
 };
 
 use moka::future::Cache;
+use reqwest::StatusCode;
 use scraper::{Html, Selector};
 use sea_orm::{
-    DeriveValueType,
+    ConnectionTrait, DeriveValueType, EntityTrait,
     sea_query::{Nullable, Value},
 };
-use serde::{Deserialize, de::Deserializer};
-use serenity::all::{
-    Colour, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedAuthor, EditInteractionResponse,
-    UserId,
+use serde::{
+    Deserialize,
+    de::{self, Deserializer},
+};
+use serde_json::json;
+use serenity::{
+    all::{
+        Colour, CommandDataOptionValue, CreateActionRow, CreateButton, CreateCommandOption,
+        CreateEmbed, CreateEmbedAuthor, EditInteractionResponse, UserId,
+    },
+    futures::future,
 };
 use serenity_commands::BasicOption;
 use time::OffsetDateTime;
 
 use crate::{
     BotResult, HTTP_CLIENT,
-    entities::{GameFormat, Map},
+    entities::{GameFormat, LinkedUserId, Map, ScrimOutcome, steam_mapping},
     error::BotError,
 };
 
@@ -35,6 +45,21 @@ fn build_rgl_cache<K: Hash + Eq + Send + Sync + 'static, V: Clone + Send + Sync
         .build()
 }
 
+/// Check that the RGL.gg API is reachable and not erroring.
+pub async fn ping() -> BotResult {
+    let resp = HTTP_CLIENT
+        .head("https://api.rgl.gg/v0/seasons/")
+        .send()
+        .await
+        .map_err(|_| BotError::RglUnavailable)?;
+
+    if resp.status().is_server_error() {
+        Err(BotError::RglUnavailable)
+    } else {
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RglProfile {
@@ -44,28 +69,50 @@ pub struct RglProfile {
     pub current_teams: RglProfileTeams,
 }
 
+static DISCORD_PROFILE_CACHE: LazyLock<Cache<UserId, Arc<RglProfile>>> = LazyLock::new(|| {
+    Cache::builder()
+        .time_to_live(std::time::Duration::from_hours(24))
+        .build()
+});
+
 impl RglProfile {
     pub async fn get(steam_id: SteamId) -> BotResult<Arc<Self>> {
-        static CACHE: LazyLock<Cache<SteamId, Arc<RglProfile>>> = LazyLock::new(build_rgl_cache);
+        static CACHE: LazyLock<Cache<SteamId, Arc<RglProfile>>> = LazyLock::new(|| {
+            Cache::builder()
+                .time_to_live(std::time::Duration::from_mins(1))
+                .build()
+        });
 
         Ok(CACHE
             .try_get_with(steam_id, async {
-                HTTP_CLIENT
+                Ok(HTTP_CLIENT
                     .get(format!("https://api.rgl.gg/v0/profile/{steam_id}"))
                     .send()
                     .await?
                     .error_for_status()?
                     .json()
-                    .await
-                    .map_err(Into::into)
+                    .await?)
             })
             .await?)
     }
 
-    pub async fn get_from_discord(user_id: UserId) -> BotResult<Arc<Self>> {
-        let steam_id = SteamId::get_from_user_id(user_id).await?;
+    pub async fn get_from_discord(
+        db: &impl ConnectionTrait,
+        user_id: UserId,
+    ) -> BotResult<Arc<Self>> {
+        Ok(DISCORD_PROFILE_CACHE
+            .try_get_with(user_id, async {
+                let steam_id = SteamId::get_from_user_id(db, user_id).await?;
+
+                Self::get(steam_id).await
+            })
+            .await?)
+    }
 
-        Self::get(steam_id).await
+    /// Forget the cached profile of a Discord user, e.g. after they link a
+    /// different Steam account.
+    pub async fn invalidate_discord(user_id: UserId) {
+        DISCORD_PROFILE_CACHE.invalidate(&user_id).await;
     }
 
     pub fn url(&self, game_format: Option<GameFormat>) -> String {
@@ -96,6 +143,11 @@ impl RglProfile {
                     .icon_url("https://liquipedia.net/commons/images/6/66/RGL_Logo.png"),
             )
             .fields([
+                (
+                    "Steam ID",
+                    format!("[`{}`]({})", self.steam_id, self.steam_id.steam_url()),
+                    false,
+                ),
                 (
                     "Sixes",
                     self.current_teams.sixes.as_ref().map_or_else(
@@ -150,6 +202,8 @@ impl RglProfileTeam {
 #[serde(rename_all = "camelCase")]
 pub struct RglTeam {
     pub season_id: SeasonId,
+    pub name: String,
+    pub players: Vec<RglTeamPlayer>,
 }
 
 impl RglTeam {
@@ -158,14 +212,96 @@ impl RglTeam {
 
         Ok(CACHE
             .try_get_with(team_id, async {
-                HTTP_CLIENT
+                Ok(HTTP_CLIENT
                     .get(format!("https://api.rgl.gg/v0/teams/{team_id}"))
                     .send()
                     .await?
                     .error_for_status()?
                     .json()
+                    .await?)
+            })
+            .await?)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RglTeamPlayer {
+    pub steam_id: SteamId,
+    pub name: String,
+    pub is_leader: bool,
+}
+
+impl RglTeamPlayer {
+    pub fn url(&self) -> String {
+        self.steam_id.rgl_url()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TeamSearchResult {
+    pub team_id: RglTeamId,
+    pub name: String,
+}
+
+/// The body returned by RGL.gg's search endpoints, which only list IDs.
+#[derive(Debug, Deserialize)]
+struct SearchResults<T> {
+    results: Vec<T>,
+}
+
+/// A team ID as listed in search results, which may be a string or a number.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SearchTeamId {
+    Number(i32),
+    String(String),
+}
+
+impl TryFrom<SearchTeamId> for RglTeamId {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(id: SearchTeamId) -> Result<Self, Self::Error> {
+        match id {
+            SearchTeamId::Number(id) => Ok(Self(id)),
+            SearchTeamId::String(id) => id.parse().map(Self),
+        }
+    }
+}
+
+impl TeamSearchResult {
+    pub async fn search(query: &str) -> BotResult<Arc<[Self]>> {
+        static CACHE: LazyLock<Cache<String, Arc<[TeamSearchResult]>>> =
+            LazyLock::new(build_rgl_cache);
+
+        Ok(CACHE
+            .try_get_with(query.to_lowercase(), async {
+                let team_ids = HTTP_CLIENT
+                    .post("https://api.rgl.gg/v0/search/teams")
+                    .json(&json!({ "nameContains": query, "take": 10, "skip": 0 }))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<SearchResults<SearchTeamId>>()
+                    .await?
+                    .results
+                    .into_iter()
+                    .filter_map(|id| RglTeamId::try_from(id).ok());
+
+                // search results only have IDs, so each team is looked up for
+                // its name. teams that fail to load are left out.
+                BotResult::Ok(
+                    future::join_all(team_ids.map(|team_id| async move {
+                        RglTeam::get(team_id).await.ok().map(|team| Self {
+                            team_id,
+                            name: team.name.clone(),
+                        })
+                    }))
                     .await
-                    .map_err(Into::into)
+                    .into_iter()
+                    .flatten()
+                    .collect(),
+                )
             })
             .await?)
     }
@@ -188,27 +324,36 @@ impl RglMatch {
 
         Ok(CACHE
             .try_get_with(match_id, async {
-                HTTP_CLIENT
+                let resp = HTTP_CLIENT
                     .get(format!("https://api.rgl.gg/v0/matches/{match_id}"))
                     .send()
-                    .await?
-                    .error_for_status()?
-                    .json()
                     .await
-                    .map_err(Into::into)
+                    .map_err(|_| BotError::RglUnavailable)?;
+
+                match resp.status() {
+                    StatusCode::NOT_FOUND => Err(BotError::RglMatchNotFound),
+                    status if status.is_server_error() => Err(BotError::RglUnavailable),
+                    _ => Ok(resp.error_for_status()?.json().await?),
+                }
             })
             .await?)
     }
 
-    pub fn opponent_team(&self, team_id: RglTeamId) -> BotResult<RglMatchTeam> {
-        match (
+    pub fn opponent_team(&self, team_id: RglTeamId) -> BotResult<RglMatchResult> {
+        let (us, them) = match (
             self.teams.0.team_id == team_id,
             self.teams.1.team_id == team_id,
         ) {
-            (true, false) => Ok(self.teams.1.clone()),
-            (false, true) => Ok(self.teams.0.clone()),
-            _ => Err(BotError::TeamNotInMatch),
-        }
+            (true, false) => (&self.teams.0, &self.teams.1),
+            (false, true) => (&self.teams.1, &self.teams.0),
+            _ => return Err(BotError::TeamNotInMatch),
+        };
+
+        Ok(RglMatchResult {
+            team: them.clone(),
+            our_score: us.score,
+            opponent_score: them.score,
+        })
     }
 }
 
@@ -217,6 +362,29 @@ impl RglMatch {
 pub struct RglMatchTeam {
     pub team_name: String,
     pub team_id: RglTeamId,
+    #[serde(default)]
+    pub score: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RglMatchResult {
+    pub team: RglMatchTeam,
+    pub our_score: Option<u32>,
+    pub opponent_score: Option<u32>,
+}
+
+impl RglMatchResult {
+    pub fn scores(&self) -> Option<(u32, u32)> {
+        self.our_score.zip(self.opponent_score)
+    }
+
+    pub fn outcome(&self) -> Option<ScrimOutcome> {
+        self.scores().map(|(ours, theirs)| match ours.cmp(&theirs) {
+            Ordering::Greater => ScrimOutcome::Win,
+            Ordering::Less => ScrimOutcome::Loss,
+            Ordering::Equal => ScrimOutcome::Tie,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -228,23 +396,41 @@ pub struct RglMatchMap {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RglSeason {
+    #[serde(rename = "name")]
+    pub season_name: String,
+    #[serde(deserialize_with = "deserialize_format_name")]
     pub format_name: GameFormat,
+    #[serde(default)]
+    pub maps: Vec<Map>,
+}
+
+fn deserialize_format_name<'de, D>(deserializer: D) -> Result<GameFormat, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+
+    GameFormat::from_format_name(&name)
+        .ok_or_else(|| de::Error::custom(format!("unknown format name: {name}")))
 }
 
 impl RglSeason {
     pub async fn get(season_id: SeasonId) -> BotResult<Arc<Self>> {
-        static CACHE: LazyLock<Cache<SeasonId, Arc<RglSeason>>> = LazyLock::new(build_rgl_cache);
+        static CACHE: LazyLock<Cache<SeasonId, Arc<RglSeason>>> = LazyLock::new(|| {
+            Cache::builder()
+                .time_to_live(std::time::Duration::from_hours(1))
+                .build()
+        });
 
         Ok(CACHE
             .try_get_with(season_id, async {
-                HTTP_CLIENT
+                Ok(HTTP_CLIENT
                     .get(format!("https://api.rgl.gg/v0/seasons/{season_id}"))
                     .send()
                     .await?
                     .error_for_status()?
                     .json()
-                    .await
-                    .map_err(Into::into)
+                    .await?)
             })
             .await?)
     }
@@ -254,7 +440,39 @@ impl RglSeason {
 pub struct SteamId(pub u64);
 
 impl SteamId {
-    pub async fn get_from_user_id(user_id: UserId) -> BotResult<Self> {
+    /// The 64-bit Steam ID of account 0 in the public universe, which every
+    /// individual account's ID is offset from.
+    #[allow(clippy::unreadable_literal)]
+    const INDIVIDUAL_BASE: u64 = 0x0110000100000000;
+
+    /// Whether the ID is for an individual account in the public universe,
+    /// which is the only kind of account that can play.
+    const fn is_individual(self) -> bool {
+        self.0 >> 32 == Self::INDIVIDUAL_BASE >> 32
+    }
+
+    /// Parse a `STEAM_X:Y:Z` or `[U:1:W]` ID into a 64-bit Steam ID.
+    fn from_legacy(s: &str) -> Option<Self> {
+        let account_id = if let Some(rest) = s.strip_prefix("STEAM_") {
+            let mut parts = rest.splitn(3, ':');
+
+            let _universe = parts.next()?.parse::<u8>().ok()?;
+            let low_bit = parts.next()?.parse::<u32>().ok().filter(|&y| y <= 1)?;
+            let high_bits = parts.next()?.parse::<u32>().ok()?;
+
+            high_bits.checked_mul(2)?.checked_add(low_bit)?
+        } else {
+            s.strip_prefix("[U:1:")?
+                .strip_suffix(']')?
+                .parse::<u32>()
+                .ok()?
+        };
+
+        Some(Self(Self::INDIVIDUAL_BASE + u64::from(account_id)))
+    }
+
+    pub async fn get_from_user_id(db: &impl ConnectionTrait, user_id: UserId) -> BotResult<Self> {
+        #[allow(clippy::duration_suboptimal_units)]
         static CACHE: LazyLock<Cache<UserId, SteamId>> = LazyLock::new(|| {
             Cache::builder()
                 .time_to_live(std::time::Duration::from_secs(24 * 60 * 60))
@@ -268,7 +486,14 @@ impl SteamId {
             .expect("static selector should be valid")
         });
 
-        CACHE
+        if let Some(mapping) = steam_mapping::Entity::find_by_id(LinkedUserId::from(user_id))
+            .one(db)
+            .await?
+        {
+            return Ok(mapping.steam_id.into());
+        }
+
+        Ok(CACHE
             .try_get_with(user_id, async {
                 let html = HTTP_CLIENT
                     .get(format!(
@@ -297,8 +522,7 @@ impl SteamId {
                     .map(SteamId)
                     .ok_or(BotError::RglProfileNotFound)
             })
-            .await
-            .map_err(Into::into)
+            .await?)
     }
 
     fn buttons(self) -> CreateActionRow {
@@ -352,6 +576,62 @@ impl From<u64> for SteamId {
     }
 }
 
+impl From<i64> for SteamId {
+    fn from(id: i64) -> Self {
+        Self(id.cast_unsigned())
+    }
+}
+
+impl From<SteamId> for i64 {
+    fn from(id: SteamId) -> Self {
+        id.0.cast_signed()
+    }
+}
+
+impl FromStr for SteamId {
+    type Err = BotError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().trim_end_matches('/');
+
+        let id = s
+            .strip_prefix("https://")
+            .or_else(|| s.strip_prefix("http://"))
+            .unwrap_or(s);
+
+        let id = id
+            .strip_prefix("steamcommunity.com/profiles/")
+            .or_else(|| id.strip_prefix("www.steamcommunity.com/profiles/"))
+            .unwrap_or(id);
+
+        id.parse()
+            .ok()
+            .map(Self)
+            .filter(|id| id.is_individual())
+            .or_else(|| Self::from_legacy(id))
+            .ok_or(BotError::InvalidSteamId)
+    }
+}
+
+impl BasicOption for SteamId {
+    type Partial = String;
+
+    fn create_option(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> CreateCommandOption {
+        String::create_option(name, description)
+    }
+
+    fn from_value(value: Option<&CommandDataOptionValue>) -> serenity_commands::Result<Self> {
+        let value = String::from_value(value)?;
+
+        value
+            .parse()
+            .map_err(|err| serenity_commands::Error::Custom(Box::new(err)))
+    }
+}
+
 impl Display for SteamId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         Display::fmt(&self.0, f)
@@ -408,6 +688,12 @@ impl Nullable for RglTeamId {
 #[serde(transparent)]
 pub struct SeasonId(pub i32);
 
+impl SeasonId {
+    pub fn url(self) -> String {
+        format!("https://rgl.gg/Public/LeagueTable?s={self}")
+    }
+}
+
 impl Display for SeasonId {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         Display::fmt(&self.0, f)
@@ -451,3 +737,51 @@ impl Display for RglMatchId {
         Display::fmt(&self.0, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn team_search_results_deserialize() {
+        let body = r#"{"results":["12187",12188,"not-an-id"],"count":3}"#;
+
+        let team_ids = serde_json::from_str::<SearchResults<SearchTeamId>>(body)
+            .unwrap()
+            .results
+            .into_iter()
+            .filter_map(|id| RglTeamId::try_from(id).ok())
+            .collect::<Vec<_>>();
+
+        assert_eq!(team_ids, [RglTeamId(12187), RglTeamId(12188)]);
+    }
+    #[test]
+    #[allow(clippy::unreadable_literal)]
+    fn steam_ids_parse_in_every_form() {
+        let id = SteamId(76561197960287930);
+
+        for input in [
+            "76561197960287930",
+            "https://steamcommunity.com/profiles/76561197960287930/",
+            "STEAM_0:0:11101",
+            "STEAM_1:0:11101",
+            "[U:1:22202]",
+        ] {
+            assert_eq!(input.parse::<SteamId>().unwrap(), id, "{input}");
+        }
+    }
+
+    #[test]
+    fn non_individual_steam_ids_are_rejected() {
+        for input in [
+            "1",
+            "103582791429521412",
+            "STEAM_0:2:11101",
+            "[U:2:22202]",
+            "[G:1:22202]",
+            "not a steam id",
+        ] {
+            assert!(input.parse::<SteamId>().is_err(), "{input}");
+        }
+    }
+}
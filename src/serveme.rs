@@ -1,31 +1,239 @@
 use std::{
     collections::{BTreeMap, HashMap},
+    fmt::{self, Display, Formatter},
     iter,
-    sync::{Arc, LazyLock},
+    sync::{Arc, LazyLock, Mutex as StdMutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
     vec,
 };
 
 use moka::future::Cache;
 use rcon::Connection;
-use reqwest::{StatusCode, header::AUTHORIZATION};
+use reqwest::{
+    RequestBuilder, Response, StatusCode,
+    header::{AUTHORIZATION, RETRY_AFTER},
+};
 use serde::{Deserialize, Serialize};
 use serenity::all::AutocompleteChoice;
 use thiserror::Error;
 use time::OffsetDateTime;
-use tokio::net::TcpStream;
+use tokio::{
+    net::TcpStream,
+    sync::Mutex,
+    time::{sleep, timeout},
+};
+use tracing::{Span, debug, info, instrument};
 
 use crate::{
     BotResult, HTTP_CLIENT,
     entities::{ConnectInfo, GameFormat, Map, MapList, ReservationId, ServemeApiKey},
     error::BotError,
+    utils::match_rank,
 };
 
-static CACHE: LazyLock<Cache<ReservationId, Arc<ReservationResponse>>> = LazyLock::new(|| {
-    Cache::builder()
-        .time_to_idle(std::time::Duration::from_secs(10))
-        .time_to_live(std::time::Duration::from_secs(60))
-        .build()
-});
+const RCON_TIMEOUT: Duration = Duration::from_secs(10);
+
+const MAX_RETRIES: u32 = 3;
+
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// Waits are capped so that a deferred interaction is still answered in a
+/// reasonable time, whatever `Retry-After` asks for.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+pub const DEFAULT_HOST: &str = "na.serveme.tf";
+
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+static DEFAULT_HOST_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+static REQUESTS_PER_MINUTE: OnceLock<u32> = OnceLock::new();
+
+/// Set the serveme.tf host used by guilds that haven't picked one, and the
+/// per-key request limit used for all requests. Only the first call has any
+/// effect.
+pub fn configure(default_host: String, requests_per_minute: u32) {
+    _ = DEFAULT_HOST_OVERRIDE.set(default_host);
+    _ = REQUESTS_PER_MINUTE.set(requests_per_minute.max(1));
+}
+
+pub fn default_host() -> &'static str {
+    DEFAULT_HOST_OVERRIDE
+        .get()
+        .map_or(DEFAULT_HOST, String::as_str)
+}
+
+fn requests_per_minute() -> u32 {
+    REQUESTS_PER_MINUTE
+        .get()
+        .copied()
+        .unwrap_or(DEFAULT_REQUESTS_PER_MINUTE)
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(capacity: u32) -> Self {
+        Self {
+            tokens: f64::from(capacity),
+            updated_at: Instant::now(),
+        }
+    }
+
+    /// Take a token if one is available, refilling the bucket at `capacity`
+    /// tokens per minute.
+    fn try_take(&mut self, capacity: u32) -> bool {
+        let now = Instant::now();
+        let capacity = f64::from(capacity);
+
+        self.tokens = capacity
+            .min(self.tokens + now.duration_since(self.updated_at).as_secs_f64() * capacity / 60.0);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+static RATE_LIMITS: LazyLock<Cache<ServemeApiKey, Arc<StdMutex<TokenBucket>>>> =
+    LazyLock::new(|| {
+        Cache::builder()
+            .time_to_idle(std::time::Duration::from_mins(10))
+            .build()
+    });
+
+/// Take a request token for the API key, failing immediately rather than
+/// waiting so that interactions are never held past Discord's deadline.
+async fn throttle(api_key: &ServemeApiKey) -> BotResult {
+    let capacity = requests_per_minute();
+
+    let bucket = RATE_LIMITS
+        .get_with_by_ref(api_key, async {
+            Arc::new(StdMutex::new(TokenBucket::full(capacity)))
+        })
+        .await;
+
+    let allowed = bucket
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .try_take(capacity);
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(BotError::ServemeRateLimited)
+    }
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Send a request, retrying up to `max_retries` times with exponential
+/// backoff (or as long as `Retry-After` says) while serveme.tf responds
+/// with 429.
+async fn send_with_retry(request: RequestBuilder, max_retries: u32) -> BotResult<Response> {
+    let mut delay = RETRY_BASE_DELAY;
+    let mut last_retry_after = None;
+
+    for attempt in 0..=max_retries {
+        let Some(request) = request.try_clone() else {
+            return Ok(request.send().await?);
+        };
+
+        let response = request.send().await?;
+
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            return Ok(response);
+        }
+
+        last_retry_after = retry_after(&response);
+
+        if attempt < max_retries {
+            sleep(last_retry_after.unwrap_or(delay).min(MAX_RETRY_DELAY)).await;
+            delay *= 2;
+        }
+    }
+
+    Err(BotError::RateLimited {
+        retry_after: last_retry_after.map(|d| d.as_secs()),
+    })
+}
+
+/// A serveme.tf API key and the host it belongs to. Keys are only valid on the
+/// host that issued them, so the two are always used together.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ServemeAccount {
+    pub host: String,
+    pub api_key: ServemeApiKey,
+}
+
+impl ServemeAccount {
+    /// Build a URL on the account's host.
+    pub fn url(&self, path: &str) -> String {
+        url(&self.host, path)
+    }
+
+    /// Only the NA host's locations are known, so other hosts take the first
+    /// server found.
+    pub fn prefers(&self, server: &Server) -> bool {
+        self.host != DEFAULT_HOST
+            || server.ip_and_port.starts_with("chi")
+            || server.ip_and_port.starts_with("ks")
+    }
+}
+
+/// Build a URL on a serveme.tf host.
+pub fn url(host: &str, path: &str) -> String {
+    format!("https://{host}{path}")
+}
+
+#[allow(clippy::duration_suboptimal_units)]
+static CACHE: LazyLock<Cache<(String, ReservationId), Arc<ReservationResponse>>> =
+    LazyLock::new(|| {
+        Cache::builder()
+            .time_to_idle(std::time::Duration::from_secs(10))
+            .time_to_live(std::time::Duration::from_secs(60))
+            .build()
+    });
+
+static RESERVATIONS_CACHE: LazyLock<Cache<ServemeAccount, Arc<[Arc<ReservationResponse>]>>> =
+    LazyLock::new(|| {
+        Cache::builder()
+            .time_to_idle(std::time::Duration::from_secs(10))
+            .time_to_live(std::time::Duration::from_mins(1))
+            .build()
+    });
+
+/// The last reservations fetched for each account, kept well past
+/// [`RESERVATIONS_CACHE`]'s expiry so autocomplete can answer from them
+/// immediately while a fresh list is fetched in the background.
+static LAST_RESERVATIONS: LazyLock<Cache<ServemeAccount, Arc<[Arc<ReservationResponse>]>>> =
+    LazyLock::new(|| {
+        Cache::builder()
+            .time_to_live(std::time::Duration::from_mins(10))
+            .build()
+    });
+
+async fn invalidate_reservations(account: &ServemeAccount) {
+    RESERVATIONS_CACHE.invalidate(account).await;
+    LAST_RESERVATIONS.invalidate(account).await;
+}
 
 #[derive(Serialize, Deserialize)]
 struct ReservationWrapper<T> {
@@ -35,7 +243,7 @@ struct ReservationWrapper<T> {
 impl<T> ReservationWrapper<T> {
     pub fn into_result(self) -> Result<T, BotError> {
         if let Some(errors) = self.reservation.errors {
-            Err(BotError::Serveme(errors))
+            Err(errors.into_bot_error())
         } else {
             Ok(self.reservation.reservation)
         }
@@ -65,9 +273,75 @@ struct ReservationErrorsWrapper<T> {
 }
 
 #[derive(Debug, Error)]
-#[error("na.serveme.tf error: {}", .0.iter().map(|(k, v)| format!("{k}: {v}")).collect::<Vec<_>>().join(", "))]
+#[error("{}", self.user_friendly())]
 pub struct ServemeError(pub HashMap<String, String>);
 
+impl ServemeError {
+    /// Map well-known validation errors to errors with actionable messages,
+    /// keeping the raw details for anything else.
+    pub fn into_bot_error(self) -> BotError {
+        let known = self.0.iter().find_map(|(key, message)| {
+            let message = message.to_lowercase();
+
+            match key.as_str() {
+                "starts_at" if message.contains("past") => Some(BotError::ReservationInPast),
+                "ends_at" if message.contains("maximum") || message.contains("too long") => {
+                    Some(BotError::ReservationTooLong)
+                }
+                "server" | "server_id"
+                    if message.contains("booked") || message.contains("available") =>
+                {
+                    Some(BotError::ServemeServerUnavailable)
+                }
+                "base" if message.contains("already have a reservation") => {
+                    Some(BotError::ReservationOverlap)
+                }
+                _ => None,
+            }
+        });
+
+        known.unwrap_or(BotError::Serveme(self))
+    }
+
+    pub fn user_friendly(&self) -> String {
+        let mut errors = self.0.iter().collect::<Vec<_>>();
+        errors.sort_unstable();
+
+        errors
+            .into_iter()
+            .map(|(key, message)| {
+                let message = message.trim().trim_end_matches('.');
+
+                let sentence = match key.as_str() {
+                    "base" => message.to_owned(),
+                    "starts_at" => format!("Reservation start time {message}"),
+                    "ends_at" => format!("Reservation end time {message}"),
+                    "server" | "server_id" => format!("Server {message}"),
+                    "first_map" => format!("First map {message}"),
+                    "password" => format!("Server password {message}"),
+                    "rcon" => format!("RCON password {message}"),
+                    "server_config" | "server_config_id" => format!("Server config {message}"),
+                    "whitelist" | "whitelist_id" | "custom_whitelist_id" => {
+                        format!("Whitelist {message}")
+                    }
+                    key => {
+                        let key = key.replace('_', " ");
+                        let mut chars = key.chars();
+
+                        chars.next().map_or_else(
+                            || message.to_owned(),
+                            |first| format!("{}{} {message}", first.to_uppercase(), chars.as_str()),
+                        )
+                    }
+                };
+
+                format!("{sentence}.")
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 impl<'de> Deserialize<'de> for ServemeError {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -96,18 +370,71 @@ pub struct FindServersRequest {
     pub ends_at: OffsetDateTime,
 }
 
+type FindServersCacheKey = (ServemeAccount, i64, i64);
+
+static FIND_SERVERS_CACHE: LazyLock<Cache<FindServersCacheKey, Arc<FindServersResponse>>> =
+    LazyLock::new(|| {
+        Cache::builder()
+            .time_to_live(std::time::Duration::from_secs(5))
+            .build()
+    });
+
 impl FindServersRequest {
-    pub async fn send(&self, api_key: &ServemeApiKey) -> BotResult<FindServersResponse> {
-        Ok(HTTP_CLIENT
-            .post("https://na.serveme.tf/api/reservations/find_servers")
-            .header(AUTHORIZATION, api_key.auth_header())
-            .json(&ReservationWrapper::from(self))
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
+    /// Find the servers available during the window. Results are briefly cached
+    /// per minute-rounded window so that hosting several games in a row doesn't
+    /// search again each time.
+    pub async fn send(&self, account: &ServemeAccount) -> BotResult<Arc<FindServersResponse>> {
+        self.send_inner(account, MAX_RETRIES).await
+    }
+
+    /// Like [`Self::send`], but without waiting to retry a rate-limited
+    /// request, for autocomplete, which Discord only waits 3 seconds for.
+    pub async fn send_without_retry(
+        &self,
+        account: &ServemeAccount,
+    ) -> BotResult<Arc<FindServersResponse>> {
+        self.send_inner(account, 0).await
+    }
+
+    async fn send_inner(
+        &self,
+        account: &ServemeAccount,
+        max_retries: u32,
+    ) -> BotResult<Arc<FindServersResponse>> {
+        Ok(FIND_SERVERS_CACHE
+            .try_get_with(self.cache_key(account), async {
+                throttle(&account.api_key).await?;
+
+                Ok(send_with_retry(
+                    HTTP_CLIENT
+                        .post(account.url("/api/reservations/find_servers"))
+                        .header(AUTHORIZATION, account.api_key.auth_header())
+                        .json(&ReservationWrapper::from(self)),
+                    max_retries,
+                )
+                .await?
+                .error_for_status()?
+                .json()
+                .await?)
+            })
             .await?)
     }
+
+    /// Drop the cached servers for the window, e.g. after a server from it
+    /// turned out to be unavailable.
+    pub async fn invalidate(&self, account: &ServemeAccount) {
+        FIND_SERVERS_CACHE
+            .invalidate(&self.cache_key(account))
+            .await;
+    }
+
+    fn cache_key(&self, account: &ServemeAccount) -> FindServersCacheKey {
+        (
+            account.clone(),
+            self.starts_at.unix_timestamp().div_euclid(60),
+            self.ends_at.unix_timestamp().div_euclid(60),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -118,6 +445,7 @@ pub struct FindServersResponse {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Server {
     pub id: u32,
+    pub name: String,
     pub ip: String,
     pub ip_and_port: String,
 }
@@ -127,16 +455,16 @@ pub struct GetReservationRequest;
 
 impl GetReservationRequest {
     pub async fn send(
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
         reservation_id: ReservationId,
     ) -> BotResult<Arc<ReservationResponse>> {
         Ok(CACHE
-            .try_get_with(reservation_id, async {
+            .try_get_with((account.host.clone(), reservation_id), async {
+                throttle(&account.api_key).await?;
+
                 Ok(HTTP_CLIENT
-                    .get(format!(
-                        "https://na.serveme.tf/api/reservations/{reservation_id}"
-                    ))
-                    .header(AUTHORIZATION, api_key.auth_header())
+                    .get(account.url(&format!("/api/reservations/{reservation_id}")))
+                    .header(AUTHORIZATION, account.api_key.auth_header())
                     .send()
                     .await?
                     .error_for_status()?
@@ -148,25 +476,22 @@ impl GetReservationRequest {
             .await?)
     }
 
-    pub async fn send_many(api_key: &ServemeApiKey) -> BotResult<Arc<[Arc<ReservationResponse>]>> {
-        static RESERVATIONS_CACHE: LazyLock<Cache<(), Arc<[Arc<ReservationResponse>]>>> =
-            LazyLock::new(|| {
-                Cache::builder()
-                    .time_to_idle(std::time::Duration::from_secs(10))
-                    .time_to_live(std::time::Duration::from_secs(60))
-                    .build()
-            });
-
+    /// Fetch all of the account's reservations. Results are cached per account
+    /// so that guilds never see each other's reservations, and are dropped
+    /// whenever a reservation is created, edited, or deleted.
+    pub async fn send_many(account: &ServemeAccount) -> BotResult<Arc<[Arc<ReservationResponse>]>> {
         #[derive(Deserialize)]
         struct ReservationsResponse {
             reservations: Vec<Arc<ReservationResponse>>,
         }
 
         let reservations = RESERVATIONS_CACHE
-            .try_get_with((), async {
+            .try_get_with(account.clone(), async {
+                throttle(&account.api_key).await?;
+
                 let reservations = HTTP_CLIENT
-                    .get("https://na.serveme.tf/api/reservations?limit=500")
-                    .header(AUTHORIZATION, api_key.auth_header())
+                    .get(account.url("/api/reservations?limit=500"))
+                    .header(AUTHORIZATION, account.api_key.auth_header())
                     .send()
                     .await?
                     .error_for_status()?
@@ -175,15 +500,51 @@ impl GetReservationRequest {
                     .reservations;
 
                 for reservation in &reservations {
-                    CACHE.insert(reservation.id, Arc::clone(reservation)).await;
+                    CACHE
+                        .insert(
+                            (account.host.clone(), reservation.id),
+                            Arc::clone(reservation),
+                        )
+                        .await;
                 }
 
-                Ok(reservations.into())
+                let reservations = Arc::<[_]>::from(reservations);
+
+                LAST_RESERVATIONS
+                    .insert(account.clone(), Arc::clone(&reservations))
+                    .await;
+
+                Ok(reservations)
             })
             .await?;
 
         Ok(reservations)
     }
+
+    /// Like [`Self::send_many`], but if the fresh list has expired, answer with
+    /// the last one fetched and refresh it in the background. Meant for
+    /// autocomplete, which has to respond within Discord's 3 second deadline.
+    pub async fn send_many_cached(
+        account: &ServemeAccount,
+    ) -> BotResult<Arc<[Arc<ReservationResponse>]>> {
+        if let Some(reservations) = RESERVATIONS_CACHE.get(account).await {
+            return Ok(reservations);
+        }
+
+        let Some(reservations) = LAST_RESERVATIONS.get(account).await else {
+            return Self::send_many(account).await;
+        };
+
+        let account = account.clone();
+
+        tokio::spawn(async move {
+            if let Err(error) = Self::send_many(&account).await {
+                debug!(?error, "failed to refresh reservations in the background");
+            }
+        });
+
+        Ok(reservations)
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -206,11 +567,23 @@ pub struct CreateReservationRequest {
 }
 
 impl CreateReservationRequest {
-    pub async fn send(&self, api_key: &ServemeApiKey) -> BotResult<Arc<ReservationResponse>> {
+    #[instrument(
+        skip_all,
+        fields(
+            server_id = self.server_id,
+            starts_at = %self.starts_at,
+            ends_at = %self.ends_at,
+            reservation_id,
+        ),
+        err
+    )]
+    pub async fn send(&self, account: &ServemeAccount) -> BotResult<Arc<ReservationResponse>> {
+        throttle(&account.api_key).await?;
+
         let reservation = Arc::new(
             HTTP_CLIENT
-                .post("https://na.serveme.tf/api/reservations")
-                .header(AUTHORIZATION, api_key.auth_header())
+                .post(account.url("/api/reservations"))
+                .header(AUTHORIZATION, account.api_key.auth_header())
                 .json(&ReservationWrapper::from(self))
                 .send()
                 .await?
@@ -220,7 +593,16 @@ impl CreateReservationRequest {
                 .into_result()?,
         );
 
-        CACHE.insert(reservation.id, Arc::clone(&reservation)).await;
+        Span::current().record("reservation_id", reservation.id.0);
+        info!("created reservation");
+
+        CACHE
+            .insert(
+                (account.host.clone(), reservation.id),
+                Arc::clone(&reservation),
+            )
+            .await;
+        invalidate_reservations(account).await;
 
         Ok(reservation)
     }
@@ -247,18 +629,40 @@ pub struct EditReservationRequest {
     pub server_config_id: Option<u32>,
 }
 
+impl From<&ReservationResponse> for EditReservationRequest {
+    /// An edit that puts a reservation back the way it was.
+    fn from(reservation: &ReservationResponse) -> Self {
+        Self {
+            starts_at: Some(reservation.starts_at),
+            ends_at: Some(reservation.ends_at),
+            first_map: reservation.first_map.clone(),
+            server_config_id: reservation.server_config_id,
+        }
+    }
+}
+
 impl EditReservationRequest {
+    #[instrument(
+        skip_all,
+        fields(
+            %reservation_id,
+            server_id,
+            starts_at = ?self.starts_at,
+            ends_at = ?self.ends_at,
+        ),
+        err
+    )]
     pub async fn send(
         &self,
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
         reservation_id: ReservationId,
     ) -> BotResult<Arc<ReservationResponse>> {
+        throttle(&account.api_key).await?;
+
         let reservation = Arc::new(
             HTTP_CLIENT
-                .patch(format!(
-                    "https://na.serveme.tf/api/reservations/{reservation_id}"
-                ))
-                .header(AUTHORIZATION, api_key.auth_header())
+                .patch(account.url(&format!("/api/reservations/{reservation_id}")))
+                .header(AUTHORIZATION, account.api_key.auth_header())
                 .json(&ReservationWrapper::from(self))
                 .send()
                 .await?
@@ -268,7 +672,16 @@ impl EditReservationRequest {
                 .into_result()?,
         );
 
-        CACHE.insert(reservation.id, Arc::clone(&reservation)).await;
+        Span::current().record("server_id", reservation.server.id);
+        info!("edited reservation");
+
+        CACHE
+            .insert(
+                (account.host.clone(), reservation.id),
+                Arc::clone(&reservation),
+            )
+            .await;
+        invalidate_reservations(account).await;
 
         Ok(reservation)
     }
@@ -279,21 +692,26 @@ impl EditReservationRequest {
 pub struct DeleteReservationRequest;
 
 impl DeleteReservationRequest {
-    #[allow(dead_code)]
+    #[instrument(skip_all, fields(%reservation_id), err)]
     pub async fn send(
-        api_key: &ServemeApiKey,
+        account: &ServemeAccount,
         reservation_id: ReservationId,
     ) -> BotResult<Option<ReservationResponse>> {
+        throttle(&account.api_key).await?;
+
         let resp = HTTP_CLIENT
-            .delete(format!(
-                "https://na.serveme.tf/api/reservations/{reservation_id}"
-            ))
-            .header(AUTHORIZATION, api_key.auth_header())
+            .delete(account.url(&format!("/api/reservations/{reservation_id}")))
+            .header(AUTHORIZATION, account.api_key.auth_header())
             .send()
             .await?
             .error_for_status()?;
 
-        CACHE.invalidate(&reservation_id).await;
+        CACHE
+            .invalidate(&(account.host.clone(), reservation_id))
+            .await;
+        invalidate_reservations(account).await;
+
+        info!("deleted reservation");
 
         if resp.status() == StatusCode::NO_CONTENT {
             Ok(None)
@@ -347,13 +765,65 @@ impl ReservationResponse {
         )
     }
 
+    /// Run an RCON command. Connections to ready servers are kept around and
+    /// reused for later commands.
+    ///
+    /// A pooled connection that fails is dropped, but the command isn't retried:
+    /// it may already have run, and commands like `changelevel` aren't safe to
+    /// run twice.
     pub async fn rcon(&self, cmd: &str) -> BotResult<String> {
-        let mut rcon_client =
-            Connection::<TcpStream>::connect(&self.server.ip_and_port, &self.rcon).await?;
+        type PooledConnection = Arc<Mutex<Connection<TcpStream>>>;
+
+        static POOL: LazyLock<Cache<(String, ReservationId), PooledConnection>> =
+            LazyLock::new(|| {
+                Cache::builder()
+                    .max_capacity(32)
+                    .time_to_idle(std::time::Duration::from_mins(5))
+                    .build()
+            });
+
+        // reservation IDs are only unique per host, so the server is part of
+        // the key.
+        let key = (self.server.ip_and_port.clone(), self.id);
+
+        let ready = self.status.is_ready();
+
+        if !ready {
+            POOL.invalidate(&key).await;
+        }
+
+        let resp = timeout(RCON_TIMEOUT, async {
+            if let Some(connection) = POOL.get(&key).await {
+                let resp = connection.lock().await.cmd(cmd).await;
+
+                if resp.is_err() {
+                    POOL.invalidate(&key).await;
+                }
+
+                return Ok(resp?);
+            }
+
+            let mut connection =
+                Connection::<TcpStream>::connect(&self.server.ip_and_port, &self.rcon).await?;
 
-        let resp = rcon_client.cmd(cmd).await?;
+            let resp = connection.cmd(cmd).await?;
+
+            if ready {
+                POOL.insert(key.clone(), Arc::new(Mutex::new(connection)))
+                    .await;
+            }
+
+            Ok(resp)
+        })
+        .await;
 
-        Ok(resp)
+        // a command cut off by the timeout leaves its response unread, which
+        // would be mistaken for the next command's response.
+        if resp.is_err() {
+            POOL.invalidate(&key).await;
+        }
+
+        resp.map_err(|_| BotError::RconTimeout)?
     }
 }
 
@@ -392,14 +862,43 @@ impl ReservationStatus {
     pub const fn is_ended(self) -> bool {
         matches!(self, Self::Ending | Self::Ended)
     }
+
+    pub const fn emoji(self) -> char {
+        match self {
+            Self::WaitingToStart | Self::Starting => '⏳',
+            Self::ServerUpdating => '🔄',
+            Self::Ready | Self::SdrReady => '🟢',
+            Self::Ending | Self::Ended => '⬛',
+            Self::Unknown => '❔',
+        }
+    }
+}
+
+impl Display for ReservationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WaitingToStart => "Waiting to start",
+            Self::Starting => "Starting",
+            Self::ServerUpdating => "Server updating, please be patient",
+            Self::Ready => "Ready",
+            Self::SdrReady => "SDR Ready",
+            Self::Ending => "Ending",
+            Self::Ended => "Ended",
+            Self::Unknown => "Unknown",
+        }
+        .fmt(f)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct MapsRequest;
 
 impl MapsRequest {
-    pub async fn send(api_key: &ServemeApiKey, format: Option<GameFormat>) -> BotResult<AllMaps> {
-        static MAP_CACHE: LazyLock<Cache<Option<GameFormat>, Arc<[Map]>>> = LazyLock::new(|| {
+    pub async fn send(account: &ServemeAccount, format: Option<GameFormat>) -> BotResult<AllMaps> {
+        type MapCacheKey = (String, Option<GameFormat>);
+
+        #[allow(clippy::duration_suboptimal_units)]
+        static MAP_CACHE: LazyLock<Cache<MapCacheKey, Arc<[Map]>>> = LazyLock::new(|| {
             Cache::builder()
                 .time_to_live(std::time::Duration::from_secs(24 * 60 * 60))
                 .build()
@@ -413,10 +912,12 @@ impl MapsRequest {
         let official_maps = Map::official_maps(format);
 
         let unofficial_maps = MAP_CACHE
-            .try_get_with(format, async {
+            .try_get_with((account.host.clone(), format), async {
+                throttle(&account.api_key).await?;
+
                 let mut maps = HTTP_CLIENT
-                    .get("https://na.serveme.tf/api/maps")
-                    .header(AUTHORIZATION, api_key.auth_header())
+                    .get(account.url("/api/maps"))
+                    .header(AUTHORIZATION, account.api_key.auth_header())
                     .send()
                     .await?
                     .error_for_status()?
@@ -444,6 +945,14 @@ pub struct AllMaps {
 }
 
 impl AllMaps {
+    /// Just the official maps, for when serveme.tf can't be asked for the rest.
+    pub fn official_only(format: Option<GameFormat>) -> Self {
+        Self {
+            official: Map::official_maps(format),
+            unofficial: Arc::new([]),
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Map> {
         self.official.keys().chain(self.unofficial.iter())
     }
@@ -503,11 +1012,19 @@ impl AllMaps {
         let per_map_choices = maps
             .iter()
             .map(|map| {
-                self.official
+                let mut choices = self
+                    .official
                     .keys()
-                    .filter(|official_map| {
-                        official_map.to_lowercase().contains(&map.to_lowercase())
+                    .filter_map(|official_map| {
+                        match_rank(map, official_map).map(|rank| (rank, official_map))
                     })
+                    .collect::<Vec<_>>();
+
+                choices.sort_by_key(|&(rank, _)| rank);
+
+                choices
+                    .into_iter()
+                    .map(|(_, official_map)| official_map)
                     .collect::<Vec<_>>()
             })
             .collect::<Vec<_>>();
@@ -537,3 +1054,289 @@ impl AllMaps {
             .map(|map| maps.iter().chain(iter::once(map)).collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn test_account(api_key: &str) -> ServemeAccount {
+        ServemeAccount {
+            host: DEFAULT_HOST.to_owned(),
+            api_key: ServemeApiKey(api_key.to_owned()),
+        }
+    }
+
+    fn user_friendly(payload: &str) -> String {
+        serde_json::from_str::<ServemeError>(payload)
+            .unwrap()
+            .user_friendly()
+    }
+
+    #[test]
+    fn known_serveme_errors_are_readable() {
+        for (payload, expected) in [
+            (
+                r#"{"starts_at": {"error": "must be in the future"}}"#,
+                "Reservation start time must be in the future.",
+            ),
+            (
+                r#"{"ends_at": {"error": "can't be more than 5 hours after the start."}}"#,
+                "Reservation end time can't be more than 5 hours after the start.",
+            ),
+            (
+                r#"{"server_id": {"error": "is already booked"}}"#,
+                "Server is already booked.",
+            ),
+            (
+                r#"{"first_map": {"error": "can't be blank"}}"#,
+                "First map can't be blank.",
+            ),
+            (
+                r#"{"base": {"error": "You already have a reservation in this timeframe"}}"#,
+                "You already have a reservation in this timeframe.",
+            ),
+            (
+                r#"{"rcon": {"error": "is too short"}, "password": {"error": "is too short"}}"#,
+                "Server password is too short. RCON password is too short.",
+            ),
+        ] {
+            assert_eq!(user_friendly(payload), expected, "{payload}");
+        }
+    }
+
+    #[test]
+    fn unknown_serveme_error_keys_are_humanized() {
+        assert_eq!(
+            user_friendly(r#"{"enable_plugins": {"error": "is not allowed"}}"#),
+            "Enable plugins is not allowed."
+        );
+    }
+
+    #[test]
+    fn bot_error_displays_user_friendly_serveme_error() {
+        let error = BotError::Serveme(
+            serde_json::from_str(r#"{"starts_at": {"error": "must be in the future"}}"#).unwrap(),
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "serveme.tf error: Reservation start time must be in the future."
+        );
+    }
+
+    fn find_servers(starts_at: OffsetDateTime, ends_at: OffsetDateTime) -> FindServersRequest {
+        FindServersRequest { starts_at, ends_at }
+    }
+
+    #[test]
+    fn find_servers_cache_key_rounds_to_the_minute() {
+        let account = test_account("key");
+
+        let window = find_servers(
+            datetime!(2024-10-16 20:45:00 UTC),
+            datetime!(2024-10-16 22:15:00 UTC),
+        );
+        let same_minute = find_servers(
+            datetime!(2024-10-16 20:45:59 UTC),
+            datetime!(2024-10-16 22:15:30 UTC),
+        );
+        let next_minute = find_servers(
+            datetime!(2024-10-16 20:46:00 UTC),
+            datetime!(2024-10-16 22:16:00 UTC),
+        );
+
+        assert_eq!(window.cache_key(&account), same_minute.cache_key(&account));
+        assert_ne!(window.cache_key(&account), next_minute.cache_key(&account));
+        assert_ne!(
+            window.cache_key(&account),
+            window.cache_key(&test_account("other"))
+        );
+    }
+
+    #[test]
+    fn only_the_na_host_filters_servers_by_location() {
+        let server = |ip_and_port: &str| Server {
+            id: 1,
+            name: "Server".to_owned(),
+            ip: String::new(),
+            ip_and_port: ip_and_port.to_owned(),
+        };
+
+        let na = test_account("key");
+        let eu = ServemeAccount {
+            host: "serveme.tf".to_owned(),
+            ..test_account("key")
+        };
+
+        assert!(na.prefers(&server("chi.serveme.tf:27015")));
+        assert!(na.prefers(&server("ks.serveme.tf:27015")));
+        assert!(!na.prefers(&server("dal.serveme.tf:27015")));
+        assert!(eu.prefers(&server("fra.serveme.tf:27015")));
+    }
+
+    #[tokio::test]
+    async fn find_servers_invalidate_drops_only_its_window() {
+        let account = test_account("invalidate-test");
+
+        let window = find_servers(
+            datetime!(2024-10-16 20:45 UTC),
+            datetime!(2024-10-16 22:15 UTC),
+        );
+        let other = find_servers(
+            datetime!(2024-10-16 21:45 UTC),
+            datetime!(2024-10-16 23:15 UTC),
+        );
+
+        for request in [&window, &other] {
+            FIND_SERVERS_CACHE
+                .insert(
+                    request.cache_key(&account),
+                    Arc::new(FindServersResponse { servers: vec![] }),
+                )
+                .await;
+        }
+
+        window.invalidate(&account).await;
+
+        assert!(!FIND_SERVERS_CACHE.contains_key(&window.cache_key(&account)));
+        assert!(FIND_SERVERS_CACHE.contains_key(&other.cache_key(&account)));
+    }
+
+    #[tokio::test]
+    async fn reservations_cached_prefers_fresh_list() {
+        let account = test_account("reservations-fresh-test");
+        let fresh = Arc::<[Arc<ReservationResponse>]>::from([]);
+
+        RESERVATIONS_CACHE
+            .insert(account.clone(), Arc::clone(&fresh))
+            .await;
+
+        let reservations = GetReservationRequest::send_many_cached(&account)
+            .await
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&reservations, &fresh));
+    }
+
+    #[tokio::test]
+    async fn reservations_cached_falls_back_to_last_list() {
+        let account = test_account("reservations-last-test");
+        let last = Arc::<[Arc<ReservationResponse>]>::from([]);
+
+        LAST_RESERVATIONS
+            .insert(account.clone(), Arc::clone(&last))
+            .await;
+
+        let reservations = GetReservationRequest::send_many_cached(&account)
+            .await
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&reservations, &last));
+    }
+
+    #[tokio::test]
+    async fn invalidate_reservations_clears_both_caches() {
+        let account = test_account("reservations-invalidate-test");
+        let other = test_account("reservations-invalidate-other");
+
+        for key in [&account, &other] {
+            RESERVATIONS_CACHE.insert(key.clone(), Arc::from([])).await;
+            LAST_RESERVATIONS.insert(key.clone(), Arc::from([])).await;
+        }
+
+        invalidate_reservations(&account).await;
+
+        assert!(!RESERVATIONS_CACHE.contains_key(&account));
+        assert!(!LAST_RESERVATIONS.contains_key(&account));
+        assert!(RESERVATIONS_CACHE.contains_key(&other));
+        assert!(LAST_RESERVATIONS.contains_key(&other));
+    }
+
+    #[test]
+    fn known_serveme_errors_map_to_bot_errors() {
+        let into_bot_error = |payload| {
+            serde_json::from_str::<ServemeError>(payload)
+                .unwrap()
+                .into_bot_error()
+        };
+
+        assert!(matches!(
+            into_bot_error(r#"{"starts_at": {"error": "can't be in the past"}}"#),
+            BotError::ReservationInPast
+        ));
+        assert!(matches!(
+            into_bot_error(r#"{"ends_at": {"error": "exceeds the maximum duration"}}"#),
+            BotError::ReservationTooLong
+        ));
+        assert!(matches!(
+            into_bot_error(r#"{"server_id": {"error": "is already booked"}}"#),
+            BotError::ServemeServerUnavailable
+        ));
+        assert!(matches!(
+            into_bot_error(
+                r#"{"base": {"error": "You already have a reservation in this timeframe"}}"#
+            ),
+            BotError::ReservationOverlap
+        ));
+        assert!(matches!(
+            into_bot_error(r#"{"starts_at": {"error": "can't be blank"}}"#),
+            BotError::Serveme(_)
+        ));
+    }
+
+    #[test]
+    fn token_bucket_allows_a_burst_then_refills() {
+        let mut bucket = TokenBucket::full(3);
+
+        assert!((0..3).all(|_| bucket.try_take(3)));
+        assert!(!bucket.try_take(3));
+
+        // 3 tokens per minute is one every 20 seconds.
+        bucket.updated_at -= Duration::from_secs(20);
+
+        assert!(bucket.try_take(3));
+        assert!(!bucket.try_take(3));
+    }
+
+    #[tokio::test]
+    async fn throttle_limits_each_api_key_separately() {
+        let api_key = ServemeApiKey("throttle-test".to_owned());
+        let other_api_key = ServemeApiKey("throttle-test-other".to_owned());
+
+        for _ in 0..requests_per_minute() {
+            throttle(&api_key).await.unwrap();
+        }
+
+        assert!(matches!(
+            throttle(&api_key).await,
+            Err(BotError::ServemeRateLimited)
+        ));
+        assert!(throttle(&other_api_key).await.is_ok());
+    }
+
+    #[test]
+    fn official_only_maps_autocomplete_without_serveme() {
+        let all_maps = AllMaps::official_only(Some(GameFormat::Sixes));
+
+        assert!(all_maps.unofficial.is_empty());
+        assert!(
+            all_maps
+                .iter()
+                .all(|map| map.is_official(Some(GameFormat::Sixes)))
+        );
+
+        let names = |query: &str| {
+            all_maps
+                .autocomplete_choices(&query.parse().unwrap(), false)
+                .into_iter()
+                .map(|choice| serde_json::to_value(choice).unwrap()["name"].clone())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names("process"), ["cp_process_f12"]);
+        assert_eq!(names("gully sunsh"), ["cp_gullywash_f9, cp_sunshine"]);
+        assert!(names("upward").is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use sea_orm::{TransactionTrait, prelude::*};
+use serenity::all::Context;
+use tracing::{error, info, instrument, warn};
+
+use crate::{Bot, BotResult, entities::team_guild};
+
+const POLL_INTERVAL: Duration = Duration::from_mins(1);
+const CLEANUP_INTERVAL: Duration = Duration::from_hours(24 * 7);
+
+pub async fn run(bot: Bot, ctx: Context) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(error) = sync_voice_channels(&bot, &ctx).await {
+            error!(?error, "failed to sync voice channels");
+        }
+
+        if let Err(error) = send_reminders(&bot, &ctx).await {
+            error!(?error, "failed to send game reminders");
+        }
+    }
+}
+
+/// Delete old games once a week, so that they don't pile up forever.
+pub async fn run_cleanup(bot: Bot) {
+    let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(error) = cleanup_old_games(&bot).await {
+            error!(?error, "failed to clean up old games");
+        }
+    }
+}
+
+#[instrument(skip(bot, ctx))]
+async fn sync_voice_channels(bot: &Bot, ctx: &Context) -> BotResult {
+    let guilds = team_guild::Entity::find()
+        .filter(team_guild::Column::GameVoiceChannelId.is_not_null())
+        .filter(team_guild::Column::ServemeApiKey.is_not_null())
+        .all(&bot.db)
+        .await?;
+
+    for guild in guilds {
+        let tx = bot.db.begin().await?;
+
+        if let Err(error) = guild.sync_voice_channel(ctx, &tx).await {
+            warn!(?guild.id, ?error, "failed to sync voice channel");
+        }
+
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(bot, ctx))]
+async fn send_reminders(bot: &Bot, ctx: &Context) -> BotResult {
+    let guilds = team_guild::Entity::find()
+        .filter(team_guild::Column::TeamRoleId.is_not_null())
+        .all(&bot.db)
+        .await?;
+
+    for guild in guilds {
+        let tx = bot.db.begin().await?;
+
+        if let Err(error) = guild
+            .send_reminders(ctx, &tx, bot.config.reminder_minutes)
+            .await
+        {
+            warn!(?guild.id, ?error, "failed to send game reminders");
+        }
+
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(bot))]
+async fn cleanup_old_games(bot: &Bot) -> BotResult {
+    let max_age = time::Duration::days(bot.config.cleanup_max_age_days);
+
+    let guilds = team_guild::Entity::find().all(&bot.db).await?;
+
+    for guild in guilds {
+        let tx = bot.db.begin().await?;
+
+        match guild.cleanup_old_games(&tx, max_age).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(?guild.id, deleted, "cleaned up old games"),
+            Err(error) => warn!(?guild.id, ?error, "failed to clean up old games"),
+        }
+
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
@@ -2,6 +2,7 @@
 
 use serenity::all::{CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage};
 use time::{Date, OffsetDateTime, Time, UtcOffset};
+use tz::TimeZoneRef;
 
 use crate::error::BotError;
 
@@ -99,6 +100,7 @@ pub fn success_response(description: impl Into<String>) -> CreateInteractionResp
     CreateInteractionResponse::Message(success_message(description))
 }
 
+#[deprecated(note = "format through the guild's timezone with `team_guild::Model::to_local`")]
 pub trait OffsetDateTimeEtExt {
     fn new_et(date: Date, time: Time) -> Self;
 
@@ -119,17 +121,14 @@ pub trait OffsetDateTimeEtExt {
     fn time_et(&self) -> Time;
 }
 
+#[allow(deprecated)]
 impl OffsetDateTimeEtExt for OffsetDateTime {
     fn new_et(date: Date, time: Time) -> Self {
         Self::new_utc(date, time).replace_with_et_offset()
     }
 
     fn et_offset(&self) -> UtcOffset {
-        let ny = tzdb::time_zone::america::NEW_YORK;
-
-        let local_time_type = ny.find_local_time_type(self.unix_timestamp()).unwrap();
-
-        UtcOffset::from_whole_seconds(local_time_type.ut_offset()).unwrap()
+        tz_offset(tzdb::time_zone::america::NEW_YORK, *self)
     }
 
     fn now_et() -> Self {
@@ -147,49 +146,17 @@ impl OffsetDateTimeEtExt for OffsetDateTime {
     }
 
     fn string_et(&self) -> String {
-        let this = self.to_et_offset();
-
-        let weekday = this.weekday();
-        let month = this.month();
-        let day = this.day();
-        let hour_24 = this.hour();
-        let hour = if hour_24 == 0 {
-            12
-        } else if hour_24 > 12 {
-            hour_24 - 12
-        } else {
-            hour_24
-        };
-        let minute = this.minute();
-        let ampm = if hour_24 >= 12 { "PM" } else { "AM" };
-
-        format!("{weekday}, {month} {day} at {hour}:{minute:02} {ampm}")
+        datetime_string(self.to_et_offset())
     }
 
     fn string_et_relative(&self) -> String {
         let this = self.to_et_offset();
 
-        let now_date = Self::now_et().date();
-        let date = if this.date() == now_date {
-            "Today".to_owned()
-        } else if this.date() == now_date.next_day().unwrap() {
-            "Tomorrow".to_owned()
-        } else {
-            format!("{}, {} {}", this.weekday(), this.month(), this.day())
-        };
-
-        let hour_24 = this.hour();
-        let hour = if hour_24 == 0 {
-            12
-        } else if hour_24 > 12 {
-            hour_24 - 12
-        } else {
-            hour_24
-        };
-        let minute = this.minute();
-        let ampm = if hour_24 >= 12 { "PM" } else { "AM" };
-
-        format!("{date} at {hour}:{minute:02} {ampm}")
+        format!(
+            "{} at {}",
+            relative_date_string(this.date(), Self::now_et().date()),
+            time_string(this.time())
+        )
     }
 
     fn date_et(&self) -> Date {
@@ -201,6 +168,52 @@ impl OffsetDateTimeEtExt for OffsetDateTime {
     }
 }
 
+pub fn tz_offset(time_zone: TimeZoneRef<'_>, datetime: OffsetDateTime) -> UtcOffset {
+    let local_time_type = time_zone
+        .find_local_time_type(datetime.unix_timestamp())
+        .unwrap();
+
+    UtcOffset::from_whole_seconds(local_time_type.ut_offset()).unwrap()
+}
+
+pub fn datetime_string(datetime: OffsetDateTime) -> String {
+    format!(
+        "{} at {}",
+        date_string(datetime.date()),
+        time_string(datetime.time())
+    )
+}
+
+pub fn relative_date_string(date: Date, today: Date) -> String {
+    match (date - today).whole_days() {
+        -1 => "Yesterday".to_owned(),
+        0 => "Today".to_owned(),
+        1 => "Tomorrow".to_owned(),
+        -6..=-2 => date.weekday().to_string(),
+        _ => date_string(date),
+    }
+}
+
+/// Formats a number of minutes as e.g. "1 minute" or "1 hour 30 minutes".
+pub fn duration_string(minutes: i64) -> String {
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("{n} {unit}")
+        } else {
+            format!("{n} {unit}s")
+        }
+    };
+
+    let hours = minutes / 60;
+    let minutes = minutes % 60;
+
+    match (hours, minutes) {
+        (0, minutes) => plural(minutes, "minute"),
+        (hours, 0) => plural(hours, "hour"),
+        (hours, minutes) => format!("{} {}", plural(hours, "hour"), plural(minutes, "minute")),
+    }
+}
+
 pub fn date_string(date: Date) -> String {
     let weekday = date.weekday();
     let month = date.month();
@@ -228,8 +241,8 @@ pub fn lfs_date_string(date: Date) -> String {
     date.weekday().to_string()[..3].to_lowercase()
 }
 
-pub fn lfs_date_string_single(date: Date) -> String {
-    if date == OffsetDateTime::now_et().date() {
+pub fn lfs_date_string_single(date: Date, today: Date) -> String {
+    if date == today {
         String::new()
     } else {
         let weekday = date.weekday().to_string()[..3].to_lowercase();
@@ -256,3 +269,93 @@ pub fn lfs_time_string(time: Time) -> String {
 
     format!("{hour}{minute}{ampm}")
 }
+
+pub fn fuzzy_match(query: &str, candidate: &str) -> bool {
+    let mut candidate = candidate.chars();
+
+    query.chars().all(|q| candidate.any(|c| c == q))
+}
+
+/// Ranks how well `query` matches `candidate`, case-insensitively. Lower is
+/// better: prefix matches, then substring matches, then subsequence matches.
+pub fn match_rank(query: &str, candidate: &str) -> Option<u8> {
+    let query = query.to_lowercase();
+    let candidate = candidate.to_lowercase();
+
+    if candidate.starts_with(&query) {
+        Some(0)
+    } else if candidate.contains(&query) {
+        Some(1)
+    } else if fuzzy_match(&query, &candidate) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn duration_string_pluralizes_and_splits_hours() {
+        assert_eq!(duration_string(0), "0 minutes");
+        assert_eq!(duration_string(1), "1 minute");
+        assert_eq!(duration_string(15), "15 minutes");
+        assert_eq!(duration_string(60), "1 hour");
+        assert_eq!(duration_string(61), "1 hour 1 minute");
+        assert_eq!(duration_string(90), "1 hour 30 minutes");
+        assert_eq!(duration_string(180), "3 hours");
+    }
+
+    #[test]
+    fn fuzzy_match_is_an_ordered_subsequence_check() {
+        assert!(fuzzy_match("", "cp_process_f12"));
+        assert!(fuzzy_match("cp_process_f12", "cp_process_f12"));
+        assert!(fuzzy_match("prcs", "cp_process_f12"));
+        assert!(fuzzy_match("cpf12", "cp_process_f12"));
+        assert!(!fuzzy_match("scorp", "cp_process_f12"));
+        assert!(!fuzzy_match("PROCESS", "cp_process_f12"));
+        assert!(!fuzzy_match("cp_process_f12x", "cp_process_f12"));
+    }
+
+    #[test]
+    fn match_rank_prefers_prefix_then_substring_then_subsequence() {
+        assert_eq!(match_rank("cp_pro", "cp_process_f12"), Some(0));
+        assert_eq!(match_rank("CP_PRO", "cp_process_f12"), Some(0));
+        assert_eq!(match_rank("process", "cp_process_f12"), Some(1));
+        assert_eq!(match_rank("prcsf", "cp_process_f12"), Some(2));
+        assert_eq!(match_rank("PRCSF", "cp_process_f12"), Some(2));
+        assert_eq!(match_rank("gully", "cp_process_f12"), None);
+    }
+
+    #[test]
+    fn relative_date_string_labels_nearby_days() {
+        let today = date!(2024 - 10 - 16); // a Wednesday
+
+        assert_eq!(relative_date_string(today, today), "Today");
+        assert_eq!(
+            relative_date_string(date!(2024 - 10 - 17), today),
+            "Tomorrow"
+        );
+        assert_eq!(
+            relative_date_string(date!(2024 - 10 - 15), today),
+            "Yesterday"
+        );
+        assert_eq!(relative_date_string(date!(2024 - 10 - 14), today), "Monday");
+        assert_eq!(
+            relative_date_string(date!(2024 - 10 - 10), today),
+            "Thursday"
+        );
+        assert_eq!(
+            relative_date_string(date!(2024 - 10 - 09), today),
+            "Wednesday, October 9"
+        );
+        assert_eq!(
+            relative_date_string(date!(2024 - 10 - 18), today),
+            "Friday, October 18"
+        );
+    }
+}
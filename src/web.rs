@@ -0,0 +1,166 @@
+use std::{convert::Infallible, net::SocketAddr};
+
+use http_body_util::Full;
+use hyper::{
+    Method, Request, Response, StatusCode,
+    body::{Bytes, Incoming},
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    server::conn::http1,
+    service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use sea_orm::{EntityTrait, PaginatorTrait};
+use serde_json::{Value, json};
+use serenity::all::GuildId;
+use subtle::ConstantTimeEq;
+use tokio::net::TcpListener;
+use tracing::{error, info};
+
+use crate::{Bot, BotResult, entities::team_guild};
+
+pub async fn run(bot: Bot, addr: SocketAddr) {
+    let listener = match TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(error) => {
+            error!(?error, %addr, "failed to bind web server");
+            return;
+        }
+    };
+
+    info!(%addr, "web server listening");
+
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(error) => {
+                error!(?error, "failed to accept web connection");
+                continue;
+            }
+        };
+
+        let bot = bot.clone();
+
+        tokio::spawn(async move {
+            let service = service_fn(|req| handle(&bot, req));
+
+            if let Err(error) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                error!(?error, "failed to serve web connection");
+            }
+        });
+    }
+}
+
+async fn handle(bot: &Bot, req: Request<Incoming>) -> Result<Response<Full<Bytes>>, Infallible> {
+    Ok(route(bot, &req).await.unwrap_or_else(|error| {
+        error!(?error, "web request failed");
+
+        json_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &json!({ "error": "internal server error" }),
+        )
+    }))
+}
+
+async fn route(bot: &Bot, req: &Request<Incoming>) -> BotResult<Response<Full<Bytes>>> {
+    let segments = req
+        .uri()
+        .path()
+        .trim_matches('/')
+        .split('/')
+        .collect::<Vec<_>>();
+
+    match (req.method(), segments.as_slice()) {
+        (&Method::GET, ["health"]) => {
+            let guilds = team_guild::Entity::find().count(&bot.db).await?;
+
+            Ok(json_response(
+                StatusCode::OK,
+                &json!({ "status": "ok", "guilds": guilds }),
+            ))
+        }
+        (&Method::GET, ["guilds", guild_id, "config"]) => {
+            if !is_authorized(bot, req) {
+                return Ok(json_response(
+                    StatusCode::UNAUTHORIZED,
+                    &json!({ "error": "unauthorized" }),
+                ));
+            }
+
+            let Some(guild_id) = guild_id
+                .parse()
+                .ok()
+                .filter(|&id| id != 0)
+                .map(GuildId::new)
+            else {
+                return Ok(not_found());
+            };
+
+            Ok(team_guild::Entity::find_by_id(guild_id)
+                .one(&bot.db)
+                .await?
+                .map_or_else(not_found, |guild| {
+                    json_response(StatusCode::OK, &config_json(&guild))
+                }))
+        }
+        _ => Ok(not_found()),
+    }
+}
+
+fn is_authorized(bot: &Bot, req: &Request<Incoming>) -> bool {
+    let Some(secret) = &bot.config.web_secret else {
+        return false;
+    };
+
+    req.headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| bool::from(token.as_bytes().ct_eq(secret.as_bytes())))
+}
+
+fn config_json(guild: &team_guild::Model) -> Value {
+    fn string(value: Option<&impl ToString>) -> Option<String> {
+        value.map(ToString::to_string)
+    }
+
+    json!({
+        "guild_id": guild.id.to_string(),
+        "rgl_team_id": string(guild.rgl_team_id.as_ref()),
+        "game_format": string(guild.game_format.as_ref()),
+        "schedule_channel_id": string(guild.schedule_channel_id.as_ref()),
+        "game_voice_channel_id": string(guild.game_voice_channel_id.as_ref()),
+        "reminder_channel_id": string(guild.reminder_channel_id.as_ref()),
+        "team_role_id": string(guild.team_role_id.as_ref()),
+        "serveme_api_key_set": guild.serveme_api_key.is_some(),
+        "serveme_host": guild.serveme_host(),
+        "scrim_division": guild.scrim_division,
+        "show_countdown": guild.show_countdown(),
+        "block_overlaps": guild.block_overlaps(),
+        "min_notice_minutes": guild.min_notice_minutes,
+        "timezone": string(guild.timezone.as_ref()),
+        "practice_config_id": guild.practice_config_id,
+        "default_maps": guild.default_maps.as_ref().map(ToString::to_string),
+        "scrim_emoji": guild.scrim_emoji,
+        "match_emoji": guild.match_emoji,
+    })
+}
+
+fn json_response(status: StatusCode, body: &Value) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        "application/json"
+            .parse()
+            .expect("static header should be valid"),
+    );
+
+    response
+}
+
+fn not_found() -> Response<Full<Bytes>> {
+    json_response(StatusCode::NOT_FOUND, &json!({ "error": "not found" }))
+}